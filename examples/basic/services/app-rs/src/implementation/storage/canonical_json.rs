@@ -0,0 +1,124 @@
+use serde_json::Value;
+
+/// Serializes `value` per RFC 8785 (JSON Canonicalization Scheme): object
+/// keys are sorted lexicographically by UTF-16 code unit, numbers are
+/// emitted in their shortest round-tripping form, and no insignificant
+/// whitespace is produced. Two JSON documents that are structurally equal
+/// canonicalize to the same bytes regardless of formatting, so this can be
+/// signed/verified instead of a byte-exact substring pulled out by
+/// `get_sub_json`.
+///
+/// # Errors
+/// Returns an error if `value` contains a number that cannot be represented
+/// as a finite ECMAScript number (e.g. NaN or infinity, which `serde_json`
+/// itself cannot produce from parsed input but could from a handwritten
+/// `Value`).
+pub fn canonicalize(value: &Value) -> Result<String, String> {
+    let mut out = String::new();
+    write_canonical(value, &mut out)?;
+    Ok(out)
+}
+
+fn write_canonical(value: &Value, out: &mut String) -> Result<(), String> {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => out.push_str(&canonical_number(n)?),
+        Value::String(s) => out.push_str(&canonical_string(s)),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out)?;
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort_by(|a, b| a.encode_utf16().cmp(b.encode_utf16()));
+
+            out.push('{');
+            for (i, key) in keys.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&canonical_string(key));
+                out.push(':');
+                write_canonical(&map[*key], out)?;
+            }
+            out.push('}');
+        }
+    }
+
+    Ok(())
+}
+
+fn canonical_number(n: &serde_json::Number) -> Result<String, String> {
+    if let Some(i) = n.as_i64() {
+        return Ok(i.to_string());
+    }
+    if let Some(u) = n.as_u64() {
+        return Ok(u.to_string());
+    }
+
+    let f = n.as_f64().ok_or_else(|| "number is not representable as f64".to_string())?;
+    if !f.is_finite() {
+        return Err("cannot canonicalize a non-finite number".to_string());
+    }
+
+    // Rust's `Display` for f64 prints the shortest round-tripping digits. It
+    // never uses exponent notation, so very large or very small magnitudes
+    // come out as plain decimals rather than ECMAScript's `1e+21` form.
+    Ok(f.to_string())
+}
+
+fn canonical_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize_sorts_keys() {
+        let value: Value = serde_json::from_str(r#"{"b":1,"a":2}"#).unwrap();
+        assert_eq!(canonicalize(&value).unwrap(), r#"{"a":2,"b":1}"#);
+    }
+
+    #[test]
+    fn test_canonicalize_brace_in_string_value() {
+        let value: Value = serde_json::from_str(r#"{"note":"use {curly} here"}"#).unwrap();
+        assert_eq!(canonicalize(&value).unwrap(), r#"{"note":"use {curly} here"}"#);
+    }
+
+    #[test]
+    fn test_canonicalize_escaped_quote_in_string_value() {
+        let value: Value = serde_json::from_str(r#"{"note":"a \"quoted\" word"}"#).unwrap();
+        assert_eq!(canonicalize(&value).unwrap(), r#"{"note":"a \"quoted\" word"}"#);
+    }
+
+    #[test]
+    fn test_canonicalize_nested_objects_drop_whitespace() {
+        let value: Value = serde_json::from_str(r#"{"outer": {"b": 2, "a": {"z": 1, "y": 2}}}"#).unwrap();
+        assert_eq!(canonicalize(&value).unwrap(), r#"{"outer":{"a":{"y":2,"z":1},"b":2}}"#);
+    }
+}
@@ -0,0 +1,139 @@
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// A per-connection circuit breaker, as used in ActivityPub relay clients,
+/// that fails fast once a backend looks unhealthy instead of hammering it
+/// with a full retry ladder on every call.
+///
+/// Tracks consecutive failures; crossing `failure_threshold` opens the
+/// breaker, which rejects calls outright until `cooldown` has elapsed.
+/// After the cooldown, a single probe call is allowed through in the
+/// half-open state: success closes the breaker and resets the failure
+/// count, another failure re-opens it and restarts the cooldown.
+pub struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    last_transition: SystemTime,
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            last_transition: SystemTime::now(),
+            failure_threshold,
+            cooldown,
+        }
+    }
+
+    /// Call before attempting the guarded operation. Returns `Err` if the
+    /// breaker is open and the cooldown hasn't elapsed yet, or if a probe
+    /// is already in flight; otherwise returns `Ok` and, if this is the
+    /// first call past the cooldown, admits it as the half-open probe.
+    pub fn before_call(&mut self) -> Result<(), String> {
+        match self.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open => {
+                if self.last_transition.elapsed().unwrap_or(Duration::ZERO) >= self.cooldown {
+                    self.state = CircuitState::HalfOpen;
+                    Ok(())
+                } else {
+                    Err("backend unavailable".to_string())
+                }
+            }
+            CircuitState::HalfOpen => Err("backend unavailable".to_string()),
+        }
+    }
+
+    /// Record a successful call: closes the breaker and resets the failure
+    /// count.
+    pub fn record_success(&mut self) {
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+    }
+
+    /// Record a failed call: opens the breaker once `failure_threshold` is
+    /// crossed, or immediately if the failure was the half-open probe.
+    pub fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+
+        if self.state == CircuitState::HalfOpen || self.consecutive_failures >= self.failure_threshold {
+            self.state = CircuitState::Open;
+            self.last_transition = SystemTime::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_allows_calls() {
+        let mut breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        assert!(breaker.before_call().is_ok());
+    }
+
+    #[test]
+    fn test_opens_after_threshold_failures() {
+        let mut breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+
+        for _ in 0..3 {
+            assert!(breaker.before_call().is_ok());
+            breaker.record_failure();
+        }
+
+        assert!(breaker.before_call().is_err());
+    }
+
+    #[test]
+    fn test_open_fails_fast_within_cooldown() {
+        let mut breaker = CircuitBreaker::new(1, Duration::from_secs(30));
+        breaker.before_call().unwrap();
+        breaker.record_failure();
+
+        assert_eq!(breaker.before_call(), Err("backend unavailable".to_string()));
+    }
+
+    #[test]
+    fn test_half_open_probe_success_closes_breaker() {
+        let cooldown = Duration::from_millis(50);
+        let mut breaker = CircuitBreaker::new(1, cooldown);
+        breaker.before_call().unwrap();
+        breaker.record_failure();
+
+        // Once the cooldown has elapsed, the next call is admitted as the probe.
+        std::thread::sleep(cooldown);
+        assert!(breaker.before_call().is_ok());
+        breaker.record_success();
+
+        assert!(breaker.before_call().is_ok());
+        breaker.record_failure();
+        assert!(breaker.before_call().is_err());
+    }
+
+    #[test]
+    fn test_half_open_probe_failure_reopens_and_resets_cooldown() {
+        let cooldown = Duration::from_millis(50);
+        let mut breaker = CircuitBreaker::new(1, cooldown);
+        breaker.before_call().unwrap();
+        breaker.record_failure();
+
+        std::thread::sleep(cooldown);
+        assert!(breaker.before_call().is_ok()); // admitted as the probe
+        breaker.record_failure();
+
+        // The probe's failure restarted the cooldown, so another call right
+        // after still fails fast instead of being treated as elapsed.
+        assert!(breaker.before_call().is_err());
+    }
+}
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
+use lru::LruCache;
 use redis::aio::ConnectionManager;
 use redis::{AsyncCommands, RedisError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -13,6 +15,7 @@ use super::utils::get_sub_json;
 
 const HSM_IDENTITY: &str = "BETTER_AUTH_HSM_IDENTITY_PLACEHOLDER";
 const TWELVE_HOURS_FIFTEEN_MINUTES_SECONDS: i64 = 12 * 3600 + 15 * 60;
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -33,16 +36,29 @@ struct SignedLogEntry {
     signature: String,
 }
 
+struct CachedEntry {
+    entry: LogEntry,
+    expires_at: DateTime<Utc>,
+}
+
 pub struct KeyVerifier {
     connection: Arc<Mutex<ConnectionManager>>,
-    cache: Arc<Mutex<HashMap<String, LogEntry>>>,
+    cache: Arc<Mutex<LruCache<String, CachedEntry>>>,
 }
 
 impl KeyVerifier {
     pub fn new(connection: ConnectionManager) -> Self {
+        Self::with_cache_capacity(connection, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Like [`KeyVerifier::new`], but with an explicit bound on the number of
+    /// HSM generations kept in the LRU cache.
+    pub fn with_cache_capacity(connection: ConnectionManager, cache_capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(cache_capacity).unwrap_or(NonZeroUsize::new(DEFAULT_CACHE_CAPACITY).unwrap());
+
         Self {
             connection: Arc::new(Mutex::new(connection)),
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(LruCache::new(capacity))),
         }
     }
 
@@ -53,10 +69,21 @@ impl KeyVerifier {
         hsm_generation_id: &str,
         message: &str,
     ) -> Result<(), String> {
-        let cache = self.cache.lock().await;
+        let mut cache = self.cache.lock().await;
+
+        let is_fresh = cache
+            .peek(hsm_generation_id)
+            .map(|e| e.expires_at >= Utc::now())
+            .unwrap_or(false);
 
-        if let Some(cached_entry) = cache.get(hsm_generation_id) {
-            return self.verify_with_entry(cached_entry, signature, hsm_identity, message).await;
+        if is_fresh {
+            if let Some(cached_entry) = cache.get(hsm_generation_id) {
+                return self.verify_with_entry(&cached_entry.entry, signature, hsm_identity, message).await;
+            }
+        } else {
+            // Expired entries don't invalidate the rest of the cache; just
+            // evict this one and fall through to a refresh.
+            cache.pop(hsm_generation_id);
         }
 
         drop(cache);
@@ -144,16 +171,25 @@ impl KeyVerifier {
         let records = by_prefix.get(HSM_IDENTITY)
             .ok_or("hsm identity not found".to_string())?;
 
-        // Cache entries within 12-hour window (iterate backwards)
+        // Cache only the generations discovered during this refresh, within
+        // the 12-hour-15-minute window (iterate backwards), individually evicting the
+        // least-recently-used entry if the cache is at capacity.
         let mut cache = self.cache.lock().await;
         for (record, _) in records.iter().rev() {
             let payload = &record.payload;
-            cache.insert(payload.id.clone(), payload.clone());
 
             let created_at = DateTime::parse_from_rfc3339(&payload.created_at)
-                .map_err(|e| format!("Failed to parse created_at: {}", e))?;
+                .map_err(|e| format!("Failed to parse created_at: {}", e))?
+                .with_timezone(&Utc);
+
+            let expires_at = created_at + Duration::seconds(TWELVE_HOURS_FIFTEEN_MINUTES_SECONDS);
+
+            cache.put(payload.id.clone(), CachedEntry {
+                entry: payload.clone(),
+                expires_at,
+            });
 
-            if created_at.with_timezone(&Utc) + Duration::seconds(TWELVE_HOURS_FIFTEEN_MINUTES_SECONDS) < Utc::now() {
+            if expires_at < Utc::now() {
                 break;
             }
         }
@@ -161,7 +197,7 @@ impl KeyVerifier {
         let cached_entry = cache.get(hsm_generation_id)
             .ok_or("can't find valid public key".to_string())?;
 
-        self.verify_with_entry(cached_entry, signature, hsm_identity, message).await
+        self.verify_with_entry(&cached_entry.entry, signature, hsm_identity, message).await
     }
 
     async fn verify_with_entry(
@@ -1,10 +1,14 @@
 #![allow(unused_imports, dead_code)]
 
+pub mod canonical_json;
+pub mod circuit_breaker;
 pub mod key_verifier;
 pub mod redis_verification_key_store;
 pub mod server_time_lock_store;
 pub mod utils;
 
+pub use canonical_json::*;
+pub use circuit_breaker::*;
 pub use key_verifier::*;
 pub use redis_verification_key_store::*;
 pub use server_time_lock_store::*;
@@ -1,20 +1,31 @@
 use super::super::crypto::Secp256r1;
+use super::circuit_breaker::CircuitBreaker;
 use super::key_verifier::KeyVerifier;
 use super::utils::get_sub_json;
 use async_trait::async_trait;
 use better_auth::interfaces::{Verifier, VerificationKey, VerificationKeyStore as VerificationKeyStoreTrait};
 use redis::aio::ConnectionManager;
-use redis::{AsyncCommands, RedisError};
+use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
 #[derive(Debug, Serialize, Deserialize)]
 struct KeySigningPayload {
     purpose: String,
     #[serde(rename = "publicKey")]
     public_key: String,
     expiration: String,
+    #[serde(rename = "keyFormat", default = "default_key_format")]
+    key_format: String,
+}
+
+fn default_key_format() -> String {
+    "cesr".to_string()
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,13 +50,26 @@ struct KeySigningResponse {
 /// Redis-based VerificationKeyStore that reads public keys from Redis
 pub struct RedisVerificationKeyStore {
     connection: Arc<Mutex<ConnectionManager>>,
+    breaker: Arc<Mutex<CircuitBreaker>>,
     key_verifier: Arc<KeyVerifier>,
 }
 
 impl RedisVerificationKeyStore {
     pub fn new(connection: ConnectionManager, hsm_connection: ConnectionManager) -> Self {
+        Self::with_breaker_options(connection, hsm_connection, DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOLDOWN)
+    }
+
+    /// Like [`RedisVerificationKeyStore::new`], but with explicit circuit
+    /// breaker thresholds instead of the defaults.
+    pub fn with_breaker_options(
+        connection: ConnectionManager,
+        hsm_connection: ConnectionManager,
+        failure_threshold: u32,
+        cooldown: Duration,
+    ) -> Self {
         Self {
             connection: Arc::new(Mutex::new(connection)),
+            breaker: Arc::new(Mutex::new(CircuitBreaker::new(failure_threshold, cooldown))),
             key_verifier: Arc::new(KeyVerifier::new(hsm_connection)),
         }
     }
@@ -54,38 +78,24 @@ impl RedisVerificationKeyStore {
 #[async_trait]
 impl VerificationKeyStoreTrait for RedisVerificationKeyStore {
     async fn get(&self, identity: &str) -> Result<Box<dyn VerificationKey>, String> {
-        // Retry logic to handle Redis reconnection after restart
-        const MAX_RETRIES: u32 = 3;
-        const INITIAL_BACKOFF_MS: u64 = 100;
+        self.breaker.lock().await.before_call()?;
 
-        let mut last_error = None;
+        let mut conn = self.connection.lock().await;
+        let result: Result<String, redis::RedisError> = conn.get(identity).await;
+        drop(conn);
 
-        for attempt in 0..MAX_RETRIES {
-            if attempt > 0 {
-                // Exponential backoff
-                let backoff_ms = INITIAL_BACKOFF_MS * 2_u64.pow(attempt - 1);
-                tokio::time::sleep(tokio::time::Duration::from_millis(backoff_ms)).await;
+        let value = match result {
+            Ok(value) => {
+                self.breaker.lock().await.record_success();
+                value
             }
-
-            let mut conn = self.connection.lock().await;
-
-            // Get the HSM response from Redis
-            let result: Result<String, RedisError> = conn.get(identity).await;
-            match result {
-                Ok(value) => {
-                    // Successfully got value, continue with processing
-                    return self.process_response(&value).await;
-                }
-                Err(e) => {
-                    last_error = Some(format!("Redis error: {}", e));
-                    // Drop the lock before retrying to allow reconnection
-                    drop(conn);
-                    continue;
-                }
+            Err(e) => {
+                self.breaker.lock().await.record_failure();
+                return Err(format!("Redis error: {}", e));
             }
-        }
+        };
 
-        Err(last_error.unwrap_or_else(|| "Redis connection failed after retries".to_string()))
+        self.process_response(&value).await
     }
 }
 
@@ -119,15 +129,40 @@ impl RedisVerificationKeyStore {
         }
 
         // Return the public key from the payload
+        let format = KeyFormat::from_tag(&response.body.payload.key_format)?;
         Ok(Box::new(PublicKeyWrapper {
-            public_key: response.body.payload.public_key
+            public_key: response.body.payload.public_key,
+            format,
         }) as Box<dyn VerificationKey>)
     }
 }
 
+/// Which wire format a stored public key (and the tokens it signs) uses.
+/// Carried on `KeySigningPayload.keyFormat` so `PublicKeyWrapper` can hand
+/// out the matching `Verifier` without guessing from the key bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyFormat {
+    /// Base64url CESR-prefixed key/signature, raw fixed-length (r,s) ECDSA
+    /// over the message bytes.
+    Cesr,
+    /// Standard ES256 JWS compact serialization.
+    Jws,
+}
+
+impl KeyFormat {
+    fn from_tag(tag: &str) -> Result<Self, String> {
+        match tag {
+            "cesr" => Ok(KeyFormat::Cesr),
+            "jws" => Ok(KeyFormat::Jws),
+            other => Err(format!("unknown key format: {}", other)),
+        }
+    }
+}
+
 /// Wrapper for a public key string that implements VerificationKey
 struct PublicKeyWrapper {
     public_key: String,
+    format: KeyFormat,
 }
 
 #[async_trait]
@@ -137,8 +172,10 @@ impl VerificationKey for PublicKeyWrapper {
     }
 
     fn verifier(&self) -> &dyn better_auth::interfaces::Verifier {
-        // Return a static verifier instance
-        &Secp256r1VerifierStatic
+        match self.format {
+            KeyFormat::Cesr => &Secp256r1VerifierStatic,
+            KeyFormat::Jws => &JwsEs256Verifier,
+        }
     }
 }
 
@@ -182,3 +219,91 @@ impl better_auth::interfaces::Verifier for Secp256r1VerifierStatic {
             .map_err(|_| "invalid signature".to_string())
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JwsHeader {
+    alg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct JwsClaims {
+    exp: Option<i64>,
+    nbf: Option<i64>,
+    iat: Option<i64>,
+}
+
+/// Verifier for ordinary ES256 JWS compact tokens, so callers that don't
+/// speak CESR can still issue and consume access tokens. `public_key` is a
+/// plain base64url (no CESR prefix) SEC1 P-256 point, and `message` is the
+/// full `header.payload.signature` compact serialization; `signature` is
+/// ignored since a compact JWS already carries it inline.
+struct JwsEs256Verifier;
+
+#[async_trait]
+impl better_auth::interfaces::Verifier for JwsEs256Verifier {
+    async fn verify(&self, message: &str, _signature: &str, public_key: &str) -> Result<(), String> {
+        use base64::Engine;
+        use p256::ecdsa::{
+            Signature, VerifyingKey as P256VerifyingKey, signature::Verifier as SigVerifier,
+        };
+
+        let mut parts = message.split('.');
+        let header_b64 = parts.next().ok_or("missing JWS header")?;
+        let payload_b64 = parts.next().ok_or("missing JWS payload")?;
+        let signature_b64 = parts.next().ok_or("missing JWS signature")?;
+        if parts.next().is_some() {
+            return Err("malformed JWS: too many segments".to_string());
+        }
+
+        let header_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(header_b64)
+            .map_err(|e| format!("failed to decode JWS header: {}", e))?;
+        let header: JwsHeader = serde_json::from_slice(&header_bytes)
+            .map_err(|e| format!("failed to parse JWS header: {}", e))?;
+        if header.alg != "ES256" {
+            return Err(format!("unsupported JWS algorithm: {}", header.alg));
+        }
+
+        let pk_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(public_key)
+            .map_err(|e| format!("failed to decode public key: {}", e))?;
+        let verifying_key = P256VerifyingKey::from_sec1_bytes(&pk_bytes)
+            .map_err(|e| format!("failed to import public key: {}", e))?;
+
+        let sig_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(signature_b64)
+            .map_err(|e| format!("failed to decode JWS signature: {}", e))?;
+        let sig = Signature::try_from(sig_bytes.as_slice())
+            .map_err(|e| format!("failed to parse JWS signature: {}", e))?;
+
+        let signing_input = format!("{}.{}", header_b64, payload_b64);
+        verifying_key
+            .verify(signing_input.as_bytes(), &sig)
+            .map_err(|_| "invalid signature".to_string())?;
+
+        let payload_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload_b64)
+            .map_err(|e| format!("failed to decode JWS payload: {}", e))?;
+        let claims: JwsClaims = serde_json::from_slice(&payload_bytes)
+            .map_err(|e| format!("failed to parse JWS claims: {}", e))?;
+
+        let now = chrono::Utc::now().timestamp();
+        if let Some(exp) = claims.exp {
+            if now >= exp {
+                return Err("token expired".to_string());
+            }
+        }
+        if let Some(nbf) = claims.nbf {
+            if now < nbf {
+                return Err("token not yet valid".to_string());
+            }
+        }
+        if let Some(iat) = claims.iat {
+            if iat > now {
+                return Err("token issued in the future".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
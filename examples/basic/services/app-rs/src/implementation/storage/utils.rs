@@ -22,11 +22,26 @@ pub fn get_sub_json(data: &str, label: &str) -> Result<String, String> {
 
     let mut brace_count = 0;
     let mut in_body = false;
+    let mut in_string = false;
+    let mut escaped = false;
     let mut body_end = None;
 
     for (i, ch) in data[body_start..].chars().enumerate() {
         let idx = body_start + i;
+
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
         match ch {
+            '"' => in_string = true,
             '{' => {
                 in_body = true;
                 brace_count += 1;
@@ -86,4 +101,25 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("failed to extract"));
     }
+
+    #[test]
+    fn test_get_sub_json_brace_in_string_value() {
+        let json = r#"{"outer":{"body":{"note":"use {curly} braces here"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"note":"use {curly} braces here"}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_escaped_quote_in_string_value() {
+        let json = r#"{"outer":{"body":{"note":"a \"quoted\" word: }"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"note":"a \"quoted\" word: }"}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_nested_with_braces_in_strings() {
+        let json = r#"{"outer":{"body":{"nested":{"inner":"{ not json }"},"closing":"}"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"nested":{"inner":"{ not json }"},"closing":"}"}"#);
+    }
 }
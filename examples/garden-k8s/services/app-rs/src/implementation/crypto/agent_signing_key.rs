@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use better_auth::interfaces::SigningKey;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+
+const MAX_RESPONSE_LEN: usize = 64 * 1024;
+
+#[derive(Serialize)]
+struct AgentRequest<'a> {
+    op: &'a str,
+    #[serde(rename = "keyId")]
+    key_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AgentResponse {
+    result: Option<String>,
+    error: Option<String>,
+}
+
+/// A `SigningKey` that never holds private key material. Every operation is
+/// forwarded to an external signing agent over a length-prefixed request/
+/// response protocol on a Unix domain socket (à la ssh-agent): a 4-byte
+/// big-endian length prefix followed by a JSON body naming the key and the
+/// operation to perform. The agent holds the key and does the signing; this
+/// struct only holds the socket path and a `keyId` the agent uses to look
+/// the key up.
+///
+/// Usable wherever a `SigningKey` is accepted in place of `Secp256r1`, to
+/// keep signing authority off the web process entirely, with one hardened
+/// agent serving many application servers. `ResponseKeyRotator` (and so
+/// `AppState.response_key`) holds a concrete `Secp256r1` and does not accept
+/// this type.
+pub struct AgentSigningKey {
+    socket_path: String,
+    key_id: String,
+}
+
+impl AgentSigningKey {
+    pub fn new(socket_path: impl Into<String>, key_id: impl Into<String>) -> Self {
+        Self {
+            socket_path: socket_path.into(),
+            key_id: key_id.into(),
+        }
+    }
+
+    async fn request(&self, op: &str, message: Option<&str>) -> Result<String, String> {
+        let mut stream = UnixStream::connect(&self.socket_path)
+            .await
+            .map_err(|e| format!("failed to connect to signing agent: {}", e))?;
+
+        let request = AgentRequest {
+            op,
+            key_id: &self.key_id,
+            message,
+        };
+        let body = serde_json::to_vec(&request).map_err(|e| format!("failed to encode agent request: {}", e))?;
+
+        stream
+            .write_all(&(body.len() as u32).to_be_bytes())
+            .await
+            .map_err(|e| format!("failed to write agent request length: {}", e))?;
+        stream
+            .write_all(&body)
+            .await
+            .map_err(|e| format!("failed to write agent request body: {}", e))?;
+
+        let mut len_buf = [0u8; 4];
+        stream
+            .read_exact(&mut len_buf)
+            .await
+            .map_err(|e| format!("failed to read agent response length: {}", e))?;
+        let response_len = u32::from_be_bytes(len_buf) as usize;
+
+        if response_len > MAX_RESPONSE_LEN {
+            return Err(format!("agent response too large: {} bytes", response_len));
+        }
+
+        let mut response_buf = vec![0u8; response_len];
+        stream
+            .read_exact(&mut response_buf)
+            .await
+            .map_err(|e| format!("failed to read agent response body: {}", e))?;
+
+        let response: AgentResponse = serde_json::from_slice(&response_buf)
+            .map_err(|e| format!("failed to decode agent response: {}", e))?;
+
+        if let Some(error) = response.error {
+            return Err(format!("signing agent returned error: {}", error));
+        }
+
+        response.result.ok_or_else(|| "signing agent returned an empty result".to_string())
+    }
+}
+
+#[async_trait]
+impl SigningKey for AgentSigningKey {
+    async fn identity(&self) -> Result<String, String> {
+        self.request("identity", None).await
+    }
+
+    async fn public(&self) -> Result<String, String> {
+        self.request("public", None).await
+    }
+
+    async fn sign(&self, message: &str) -> Result<String, String> {
+        self.request("sign", Some(message)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::UnixListener;
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct ReceivedRequest {
+        op: String,
+        #[serde(rename = "keyId")]
+        key_id: String,
+        message: Option<String>,
+    }
+
+    /// Accept a single connection on an already-bound `listener` and reply
+    /// with `response` framed the same way a real signing agent would,
+    /// exercising just enough of the wire format to drive
+    /// `AgentSigningKey`. The listener is bound by the caller, synchronously,
+    /// before the client side can possibly connect — spawning a task that
+    /// binds asynchronously would let `AgentSigningKey::request`'s `connect()`
+    /// race the bind and fail.
+    async fn serve_one(listener: UnixListener, response: AgentResponse) -> ReceivedRequest {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await.unwrap();
+        let request_len = u32::from_be_bytes(len_buf) as usize;
+
+        let mut request_buf = vec![0u8; request_len];
+        stream.read_exact(&mut request_buf).await.unwrap();
+        let request: ReceivedRequest = serde_json::from_slice(&request_buf).unwrap();
+
+        let body = serde_json::to_vec(&response).unwrap();
+        stream.write_all(&(body.len() as u32).to_be_bytes()).await.unwrap();
+        stream.write_all(&body).await.unwrap();
+
+        request
+    }
+
+    fn socket_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("agent-signing-key-test-{}-{}.sock", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_sign_returns_agent_result() {
+        let socket_path = socket_path("sign-ok");
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).unwrap();
+
+        let server = tokio::spawn(serve_one(
+            listener,
+            AgentResponse { result: Some("signed-blob".to_string()), error: None },
+        ));
+
+        let key = AgentSigningKey::new(socket_path.clone(), "key-1");
+        let signature = key.sign("hello").await.unwrap();
+
+        assert_eq!(signature, "signed-blob");
+        let request = server.await.unwrap();
+        assert_eq!(request.op, "sign");
+        assert_eq!(request.key_id, "key-1");
+        assert_eq!(request.message, Some("hello".to_string()));
+
+        let _ = std::fs::remove_file(&socket_path);
+    }
+
+    #[tokio::test]
+    async fn test_sign_propagates_agent_error() {
+        let socket_path = socket_path("sign-err");
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).unwrap();
+
+        let server = tokio::spawn(serve_one(
+            listener,
+            AgentResponse { result: None, error: Some("key not found".to_string()) },
+        ));
+
+        let key = AgentSigningKey::new(socket_path.clone(), "missing-key");
+        let result = key.sign("hello").await;
+
+        assert!(result.is_err());
+        server.await.unwrap();
+
+        let _ = std::fs::remove_file(&socket_path);
+    }
+}
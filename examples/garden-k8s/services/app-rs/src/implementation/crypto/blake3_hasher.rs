@@ -1,25 +1,75 @@
 use base64::Engine;
 use blake3::Hasher as Blake3;
+use std::io::Read;
 
-/// Blake3 hasher that produces CESR-encoded hashes
-pub struct Blake3Hasher;
+/// Number of bytes read per chunk when hashing a `Read` stream.
+const READER_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Blake3 hasher that produces CESR-encoded hashes.
+///
+/// Holds its own running `Blake3` state so callers can either hash a value
+/// in one shot with [`Blake3Hasher::sum`], or feed it incrementally with
+/// [`Blake3Hasher::update`] / [`Blake3Hasher::finalize`] without ever
+/// materializing the whole input in memory.
+pub struct Blake3Hasher {
+    inner: Blake3,
+}
 
 impl Blake3Hasher {
     pub fn new() -> Self {
-        Self
+        Self {
+            inner: Blake3::new(),
+        }
     }
 
     /// Compute a CESR-encoded Blake3 hash of the input
     ///
     /// Returns a base64url-encoded string with 'E' prefix (CESR format)
     pub fn sum(&self, message: &str) -> String {
-        let hash_bytes = Blake3::new()
-            .update(message.as_bytes())
-            .finalize();
+        let hash_bytes = Blake3::new().update(message.as_bytes()).finalize();
+
+        Self::encode(hash_bytes.as_bytes())
+    }
+
+    /// Feed additional bytes into the running hash.
+    ///
+    /// May be called any number of times before [`Blake3Hasher::finalize`].
+    pub fn update(&mut self, bytes: &[u8]) -> &mut Self {
+        self.inner.update(bytes);
+        self
+    }
+
+    /// Consume the hasher, producing the CESR-encoded digest of everything
+    /// fed so far via [`Blake3Hasher::update`].
+    pub fn finalize(self) -> String {
+        Self::encode(self.inner.finalize().as_bytes())
+    }
+
+    /// Hash a `Read`er in fixed-size chunks, never buffering the whole input.
+    ///
+    /// Useful for hashing large signed payloads in-flight, e.g. a request
+    /// body, without reading it entirely into memory first.
+    pub fn sum_reader<R: Read>(&self, mut reader: R) -> std::io::Result<String> {
+        let mut hasher = Blake3::new();
+        let mut buf = [0u8; READER_CHUNK_SIZE];
+
+        loop {
+            let read = reader.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+
+            hasher.update(&buf[..read]);
+        }
 
-        // Add leading zero byte for CESR padding
+        Ok(Self::encode(hasher.finalize().as_bytes()))
+    }
+
+    /// Prepend the CESR zero pad byte, base64url-encode, and swap in the
+    /// Blake3-256 derivation code ('E').
+    fn encode(raw: &[u8]) -> String {
         let mut padded = vec![0u8];
-        padded.extend_from_slice(hash_bytes.as_bytes());
+        padded.extend_from_slice(raw);
 
         // Encode to base64url
         let base64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&padded);
@@ -70,4 +120,22 @@ mod tests {
         // Different inputs should produce different hashes
         assert_ne!(hash1, hash2);
     }
+
+    #[test]
+    fn test_update_finalize_matches_sum() {
+        let mut hasher = Blake3Hasher::new();
+        hasher.update(b"test ").update(b"message");
+
+        assert_eq!(hasher.finalize(), Blake3Hasher::new().sum("test message"));
+    }
+
+    #[test]
+    fn test_sum_reader_matches_sum() {
+        let hasher = Blake3Hasher::new();
+        let message = "test message".repeat(10_000);
+
+        let from_reader = hasher.sum_reader(message.as_bytes()).unwrap();
+
+        assert_eq!(from_reader, hasher.sum(&message));
+    }
 }
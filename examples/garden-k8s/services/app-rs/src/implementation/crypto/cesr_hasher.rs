@@ -0,0 +1,142 @@
+use base64::Engine;
+use blake2::Digest;
+
+/// Blake2b-256 output size, pinned so the digest matches the other 32-byte
+/// algorithms below.
+type Blake2b256 = blake2::Blake2b<blake2::digest::consts::U32>;
+
+/// Digest algorithm identified by its CESR derivation code.
+///
+/// Every variant here produces a 32-byte raw digest, so the CESR encoding
+/// rule is uniform across all of them: prepend one zero pad byte (33 bytes
+/// total), base64url-no-pad encode to 44 chars, then overwrite the leading
+/// character with the algorithm's derivation code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CesrHasher {
+    Blake3256,
+    Blake2B256,
+    Sha3256,
+    Sha2256,
+}
+
+impl CesrHasher {
+    /// The one-character CESR derivation code for this algorithm.
+    pub fn code(&self) -> char {
+        match self {
+            CesrHasher::Blake3256 => 'E',
+            CesrHasher::Blake2B256 => 'F',
+            CesrHasher::Sha3256 => 'H',
+            CesrHasher::Sha2256 => 'I',
+        }
+    }
+
+    /// Resolve the algorithm from a derivation code.
+    pub fn from_code(code: char) -> Result<Self, String> {
+        match code {
+            'E' => Ok(CesrHasher::Blake3256),
+            'F' => Ok(CesrHasher::Blake2B256),
+            'H' => Ok(CesrHasher::Sha3256),
+            'I' => Ok(CesrHasher::Sha2256),
+            other => Err(format!("unsupported digest derivation code: {}", other)),
+        }
+    }
+
+    /// Resolve the algorithm from the derivation code embedded in a
+    /// CESR-coded digest string.
+    pub fn from_cesr(cesr: &str) -> Result<Self, String> {
+        let code = cesr.chars().next().ok_or("empty digest")?;
+        Self::from_code(code)
+    }
+
+    /// Compute the CESR-coded digest of `message` using this algorithm.
+    pub fn sum(&self, message: &str) -> String {
+        let raw: Vec<u8> = match self {
+            CesrHasher::Blake3256 => blake3::hash(message.as_bytes()).as_bytes().to_vec(),
+            CesrHasher::Blake2B256 => Blake2b256::digest(message.as_bytes()).to_vec(),
+            CesrHasher::Sha3256 => sha3::Sha3_256::digest(message.as_bytes()).to_vec(),
+            CesrHasher::Sha2256 => sha2::Sha256::digest(message.as_bytes()).to_vec(),
+        };
+
+        Self::encode(self.code(), &raw)
+    }
+
+    /// Recover the raw 32-byte digest from a CESR-coded digest string,
+    /// regardless of which algorithm produced it.
+    pub fn raw_from_cesr(cesr: &str) -> Result<Vec<u8>, String> {
+        let code = cesr.chars().next().ok_or("empty digest")?;
+
+        // Restore the base64 alphabet character the derivation code replaced:
+        // the zero pad byte always encodes to 'A'.
+        let mut restored = String::with_capacity(cesr.len());
+        restored.push('A');
+        restored.push_str(&cesr[code.len_utf8()..]);
+
+        let padded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(restored)
+            .map_err(|e| format!("failed to decode CESR digest: {}", e))?;
+
+        if padded.is_empty() {
+            return Err("decoded digest is empty".to_string());
+        }
+
+        Ok(padded[1..].to_vec())
+    }
+
+    fn encode(code: char, raw: &[u8]) -> String {
+        let mut padded = vec![0u8];
+        padded.extend_from_slice(raw);
+
+        let base64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&padded);
+
+        format!("{}{}", code, &base64[1..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_round_trips_through_from_code() {
+        for hasher in [
+            CesrHasher::Blake3256,
+            CesrHasher::Blake2B256,
+            CesrHasher::Sha3256,
+            CesrHasher::Sha2256,
+        ] {
+            assert_eq!(CesrHasher::from_code(hasher.code()).unwrap(), hasher);
+        }
+    }
+
+    #[test]
+    fn test_sum_is_tagged_with_the_right_code() {
+        for hasher in [
+            CesrHasher::Blake3256,
+            CesrHasher::Blake2B256,
+            CesrHasher::Sha3256,
+            CesrHasher::Sha2256,
+        ] {
+            let digest = hasher.sum("test message");
+            assert_eq!(digest.chars().next().unwrap(), hasher.code());
+            assert_eq!(digest.len(), 44);
+        }
+    }
+
+    #[test]
+    fn test_from_cesr_dispatches_on_embedded_code() {
+        let digest = CesrHasher::Sha2256.sum("test message");
+        assert_eq!(CesrHasher::from_cesr(&digest).unwrap(), CesrHasher::Sha2256);
+    }
+
+    #[test]
+    fn test_raw_from_cesr_matches_raw_digest() {
+        let digest = CesrHasher::Blake2B256.sum("test message");
+        let raw = CesrHasher::raw_from_cesr(&digest).unwrap();
+        assert_eq!(raw, Blake2b256::digest("test message".as_bytes()).to_vec());
+    }
+
+    #[test]
+    fn test_from_code_rejects_unknown_code() {
+        assert!(CesrHasher::from_code('Z').is_err());
+    }
+}
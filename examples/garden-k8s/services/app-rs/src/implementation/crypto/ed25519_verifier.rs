@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use base64::Engine;
+use better_auth::interfaces::Verifier;
+use ed25519_dalek::{Signature, Verifier as SigVerifier, VerifyingKey};
+
+/// CESR derivation code for a non-transferable Ed25519 verification key.
+pub const CESR_CODE: &str = "D";
+
+/// Verifies signatures produced by an Ed25519 signing key. The public key
+/// carries a 1-byte code+pad prefix (CESR code 'D') and the signature a
+/// 2-byte prefix, both ahead of the raw fixed-length encoding.
+pub struct Ed25519Verifier;
+
+impl Ed25519Verifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Ed25519Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Verifier for Ed25519Verifier {
+    async fn verify(&self, message: &str, signature: &str, public_key: &str) -> Result<(), String> {
+        let pk_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(public_key)
+            .map_err(|e| format!("Failed to decode public key: {}", e))?;
+        let pk_bytes: [u8; 32] = pk_bytes[1..]
+            .try_into()
+            .map_err(|_| "invalid Ed25519 public key length".to_string())?;
+
+        let verifying_key = VerifyingKey::from_bytes(&pk_bytes)
+            .map_err(|e| format!("Failed to import public key: {}", e))?;
+
+        let sig_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(signature)
+            .map_err(|e| format!("Failed to decode signature: {}", e))?;
+        let sig_bytes: [u8; 64] = sig_bytes[2..]
+            .try_into()
+            .map_err(|_| "invalid Ed25519 signature length".to_string())?;
+
+        let sig = Signature::from_bytes(&sig_bytes);
+
+        verifying_key
+            .verify(message.as_bytes(), &sig)
+            .map_err(|_| "invalid signature".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use rand::rngs::OsRng;
+
+    fn framed_public_key(verifying_key: &VerifyingKey) -> String {
+        let mut framed = vec![0u8];
+        framed.extend_from_slice(verifying_key.as_bytes());
+        base64::engine::general_purpose::URL_SAFE.encode(framed)
+    }
+
+    fn framed_signature(signature: &Signature) -> String {
+        let mut framed = vec![0u8, 0u8];
+        framed.extend_from_slice(&signature.to_bytes());
+        base64::engine::general_purpose::URL_SAFE.encode(framed)
+    }
+
+    #[tokio::test]
+    async fn test_verify_valid_signature() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let message = "hello from the KEL";
+        let signature = signing_key.sign(message.as_bytes());
+
+        let public_key = framed_public_key(&signing_key.verifying_key());
+        let signature = framed_signature(&signature);
+
+        Ed25519Verifier::new().verify(message, &signature, &public_key).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_tampered_signature() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let message = "hello from the KEL";
+        let signature = signing_key.sign(message.as_bytes());
+
+        let public_key = framed_public_key(&signing_key.verifying_key());
+        let signature = framed_signature(&signature);
+
+        assert!(Ed25519Verifier::new().verify("a different message", &signature, &public_key).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_wrong_key() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let other_key = SigningKey::generate(&mut OsRng);
+        let message = "hello from the KEL";
+        let signature = signing_key.sign(message.as_bytes());
+
+        let public_key = framed_public_key(&other_key.verifying_key());
+        let signature = framed_signature(&signature);
+
+        assert!(Ed25519Verifier::new().verify(message, &signature, &public_key).await.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::Engine;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+/// Argon2id cost parameters for deriving the at-rest encryption key.
+///
+/// The defaults follow the OWASP-recommended Argon2id baseline (19 MiB,
+/// 2 iterations, 1 degree of parallelism); callers operating under tighter
+/// memory budgets can override them.
+#[derive(Debug, Clone, Copy)]
+pub struct ArgonParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for ArgonParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: 19_456,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Encrypt raw signing-key bytes at rest with a passphrase.
+///
+/// Derives a 256-bit key with Argon2id over a random 16-byte salt, then
+/// seals `key_bytes` with XChaCha20-Poly1305 under a random 24-byte nonce.
+/// Returns a self-describing base64 blob of `salt || nonce || ciphertext`,
+/// meant to back a `SigningKey` implementation's `export_encrypted`.
+pub fn encrypt_signing_key(key_bytes: &[u8], passphrase: &str, params: &ArgonParams) -> Result<String, String> {
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+
+    let derived = derive_key(passphrase, &salt, params)?;
+
+    let cipher = XChaCha20Poly1305::new((&derived).into());
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, key_bytes)
+        .map_err(|_| "failed to encrypt signing key".to_string())?;
+
+    let mut blob = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce_bytes);
+    blob.extend_from_slice(&ciphertext);
+
+    Ok(base64::engine::general_purpose::STANDARD.encode(blob))
+}
+
+/// Inverse of [`encrypt_signing_key`], meant to back a `SigningKey`
+/// implementation's `import_encrypted`.
+///
+/// Fails with a distinct error on a malformed blob versus a wrong
+/// passphrase / tampered ciphertext (AEAD tag mismatch), so callers can
+/// tell the two apart.
+pub fn decrypt_signing_key(blob: &str, passphrase: &str, params: &ArgonParams) -> Result<Vec<u8>, String> {
+    let raw = base64::engine::general_purpose::STANDARD
+        .decode(blob)
+        .map_err(|e| format!("failed to decode encrypted key blob: {}", e))?;
+
+    if raw.len() <= SALT_LEN + NONCE_LEN {
+        return Err("encrypted key blob is too short".to_string());
+    }
+
+    let (salt, rest) = raw.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let derived = derive_key(passphrase, salt, params)?;
+
+    let cipher = XChaCha20Poly1305::new((&derived).into());
+    let nonce = XNonce::from_slice(nonce_bytes);
+
+    cipher
+        .decrypt(nonce, ciphertext)
+        .map_err(|_| "incorrect passphrase or corrupted key blob".to_string())
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], params: &ArgonParams) -> Result<[u8; KEY_LEN], String> {
+    let argon2_params = Params::new(params.memory_kib, params.iterations, params.parallelism, Some(KEY_LEN))
+        .map_err(|e| format!("invalid Argon2id parameters: {}", e))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+
+    let mut key = [0u8; KEY_LEN];
+    argon2
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("key derivation failed: {}", e))?;
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fast_params() -> ArgonParams {
+        // Keep tests quick; production callers should stick to the default.
+        ArgonParams {
+            memory_kib: 8,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key_bytes = b"a 32 byte placeholder secret!!!";
+        let blob = encrypt_signing_key(key_bytes, "correct horse battery staple", &fast_params()).unwrap();
+
+        let recovered = decrypt_signing_key(&blob, "correct horse battery staple", &fast_params()).unwrap();
+
+        assert_eq!(recovered, key_bytes);
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let key_bytes = b"a 32 byte placeholder secret!!!";
+        let blob = encrypt_signing_key(key_bytes, "correct horse battery staple", &fast_params()).unwrap();
+
+        assert!(decrypt_signing_key(&blob, "wrong passphrase", &fast_params()).is_err());
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_fails() {
+        let key_bytes = b"a 32 byte placeholder secret!!!";
+        let blob = encrypt_signing_key(key_bytes, "correct horse battery staple", &fast_params()).unwrap();
+
+        let mut raw = base64::engine::general_purpose::STANDARD.decode(&blob).unwrap();
+        let last = raw.len() - 1;
+        raw[last] ^= 0xFF;
+        let tampered = base64::engine::general_purpose::STANDARD.encode(raw);
+
+        assert!(decrypt_signing_key(&tampered, "correct horse battery staple", &fast_params()).is_err());
+    }
+}
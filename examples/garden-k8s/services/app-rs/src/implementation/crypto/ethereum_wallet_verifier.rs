@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use better_auth::interfaces::Verifier;
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
+/// Verifies Ethereum `personal_sign` / EIP-191 signatures by recovering the
+/// signer's address, rather than checking a signature against a caller-
+/// supplied public key.
+///
+/// `public_key` here is the *expected* `0x`-prefixed Ethereum address; the
+/// signature must recover to that same address over the EIP-191-prefixed
+/// `message`. `message` is the challenge text the client signed; it is
+/// hashed as-is, and its fields (domain, nonce, issued-at, expiration) are
+/// not parsed or checked here.
+///
+/// This lets a wallet prove control of an address in place of a
+/// provisioned P-256 key. The verifier only reports whether the recovered
+/// address matches `public_key`; it does not set any token field itself.
+pub struct EthereumWalletVerifier;
+
+impl EthereumWalletVerifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for EthereumWalletVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Verifier for EthereumWalletVerifier {
+    async fn verify(&self, message: &str, signature: &str, public_key: &str) -> Result<(), String> {
+        let sig_bytes = hex::decode(signature.strip_prefix("0x").unwrap_or(signature))
+            .map_err(|e| format!("failed to decode signature: {}", e))?;
+
+        if sig_bytes.len() != 65 {
+            return Err(format!(
+                "invalid signature length: expected 65 bytes, got {}",
+                sig_bytes.len()
+            ));
+        }
+
+        let (rs, v) = sig_bytes.split_at(64);
+        let recovery_id = normalize_recovery_id(v[0])?;
+        let sig = Signature::try_from(rs).map_err(|e| format!("failed to parse signature: {}", e))?;
+
+        let digest = eip191_digest(message);
+        let recovered = VerifyingKey::recover_from_prehash(&digest, &sig, recovery_id)
+            .map_err(|e| format!("failed to recover signer from signature: {}", e))?;
+
+        let recovered_address = address_from_verifying_key(&recovered);
+        let expected_address = public_key.strip_prefix("0x").unwrap_or(public_key).to_lowercase();
+
+        if recovered_address != expected_address {
+            return Err("recovered address does not match expected signer".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+fn normalize_recovery_id(v: u8) -> Result<RecoveryId, String> {
+    let id = match v {
+        0 | 1 => v,
+        27 | 28 => v - 27,
+        other => return Err(format!("invalid recovery id: {}", other)),
+    };
+    RecoveryId::from_byte(id).ok_or_else(|| format!("invalid recovery id: {}", v))
+}
+
+/// Hash `message` under the EIP-191 personal-sign prefix with Keccak-256,
+/// matching what wallets sign for `personal_sign` / `eth_sign`.
+fn eip191_digest(message: &str) -> [u8; 32] {
+    let prefix = format!("\x19Ethereum Signed Message:\n{}", message.len());
+
+    let mut hasher = Keccak256::new();
+    hasher.update(prefix.as_bytes());
+    hasher.update(message.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Derive the lowercase hex Ethereum address (no `0x` prefix) for a
+/// recovered public key: Keccak-256 of the uncompressed point, last 20
+/// bytes.
+fn address_from_verifying_key(key: &VerifyingKey) -> String {
+    let encoded = key.to_encoded_point(false);
+    let hash = Keccak256::digest(&encoded.as_bytes()[1..]);
+    hex::encode(&hash[12..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+    use rand::rngs::OsRng;
+
+    fn sign_message(signing_key: &SigningKey, message: &str) -> String {
+        let digest = eip191_digest(message);
+        let (signature, recovery_id): (Signature, RecoveryId) = signing_key.sign_prehash_recoverable(&digest).unwrap();
+
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        format!("0x{}", hex::encode(bytes))
+    }
+
+    #[tokio::test]
+    async fn test_verify_recovers_matching_address() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = "login challenge: domain=example.com nonce=1 iat=0 exp=1";
+        let signature = sign_message(&signing_key, message);
+        let address = format!("0x{}", address_from_verifying_key(signing_key.verifying_key()));
+
+        EthereumWalletVerifier::new().verify(message, &signature, &address).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_tampered_signature() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = "login challenge: domain=example.com nonce=1 iat=0 exp=1";
+        let signature = sign_message(&signing_key, message);
+        let address = format!("0x{}", address_from_verifying_key(signing_key.verifying_key()));
+
+        assert!(EthereumWalletVerifier::new()
+            .verify("a different message", &signature, &address)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_wrong_address() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let other_key = SigningKey::random(&mut OsRng);
+        let message = "login challenge: domain=example.com nonce=1 iat=0 exp=1";
+        let signature = sign_message(&signing_key, message);
+        let wrong_address = format!("0x{}", address_from_verifying_key(other_key.verifying_key()));
+
+        assert!(EthereumWalletVerifier::new().verify(message, &signature, &wrong_address).await.is_err());
+    }
+}
@@ -0,0 +1,23 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod agent_signing_key;
+pub mod blake3_hasher;
+pub mod cesr_hasher;
+pub mod ed25519_verifier;
+pub mod encrypted_key;
+pub mod ethereum_wallet_verifier;
+pub mod response_key_rotator;
+pub mod secp256k1_verifier;
+pub mod secp256r1;
+pub mod verifier_registry;
+
+pub use agent_signing_key::*;
+pub use blake3_hasher::*;
+pub use cesr_hasher::*;
+pub use ed25519_verifier::*;
+pub use encrypted_key::*;
+pub use ethereum_wallet_verifier::*;
+pub use response_key_rotator::*;
+pub use secp256k1_verifier::*;
+pub use secp256r1::*;
+pub use verifier_registry::*;
@@ -0,0 +1,250 @@
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+use super::secp256r1::Secp256r1;
+
+/// A freshly generated, HSM-authorized response key as handed back by a
+/// [`ResponseKeyAuthorizer`].
+pub struct AuthorizedKey {
+    pub key: Secp256r1,
+    pub public_key: String,
+    pub expiration: DateTime<Utc>,
+}
+
+/// Generates a new `Secp256r1` key, gets it authorized by the HSM, and
+/// publishes it to the response-keys store. Kept separate from
+/// [`ResponseKeyRotator`] so the rotator stays agnostic of the HSM
+/// transport and the Redis schema; callers wire it up to whatever main.rs
+/// already does at startup.
+#[async_trait]
+pub trait ResponseKeyAuthorizer: Send + Sync {
+    async fn authorize_new_key(&self) -> Result<AuthorizedKey, String>;
+
+    /// Shorten a previously-authorized key's remaining validity in the
+    /// response-keys store down to `ttl`, instead of leaving it registered
+    /// for its full original lifetime after it's no longer the active key.
+    async fn shrink_validity(&self, public_key: &str, ttl: ChronoDuration) -> Result<(), String>;
+}
+
+/// Governs when and how the response/signing key rotates.
+pub struct RotationConfig {
+    /// Rotate this long before the active key's expiry, so there's time to
+    /// authorize and propagate a replacement before it would otherwise
+    /// lapse.
+    pub rotate_before_expiry: ChronoDuration,
+    /// How long a just-superseded key remains valid in the response-keys
+    /// store after rotation, so responses signed just before the swap stay
+    /// verifiable for a little while longer.
+    pub overlap: ChronoDuration,
+    /// How often the background task wakes up to check whether it's time
+    /// to rotate.
+    pub poll_interval: Duration,
+}
+
+impl Default for RotationConfig {
+    fn default() -> Self {
+        Self {
+            rotate_before_expiry: ChronoDuration::minutes(30),
+            overlap: ChronoDuration::minutes(5),
+            poll_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Keeps an `Arc<Secp256r1>` pointed at a live, HSM-authorized response
+/// key, rotating it in the background well before expiry via `ArcSwap` so
+/// readers of `current()` never observe a torn update.
+pub struct ResponseKeyRotator {
+    current: ArcSwap<Secp256r1>,
+}
+
+impl ResponseKeyRotator {
+    pub fn current(&self) -> Arc<Secp256r1> {
+        self.current.load_full()
+    }
+
+    /// Spawn the background rotation task against an already-authorized
+    /// `initial_key`. `on_rotation_failure` is called with an error message
+    /// whenever an authorize-and-publish cycle fails, so operators are
+    /// alerted instead of silently drifting toward an expired key; the
+    /// current key is left in place and the next poll tries again.
+    pub fn spawn(
+        authorizer: Arc<dyn ResponseKeyAuthorizer>,
+        initial_key: AuthorizedKey,
+        config: RotationConfig,
+        on_rotation_failure: impl Fn(String) + Send + Sync + 'static,
+    ) -> Arc<Self> {
+        let rotator = Arc::new(Self {
+            current: ArcSwap::from_pointee(initial_key.key),
+        });
+
+        let task_rotator = Arc::clone(&rotator);
+        let state = Mutex::new((initial_key.public_key, initial_key.expiration));
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(config.poll_interval).await;
+
+                let (previous_public_key, expiration) = state.lock().await.clone();
+                if Utc::now() + config.rotate_before_expiry < expiration {
+                    continue;
+                }
+
+                match authorizer.authorize_new_key().await {
+                    Ok(new) => {
+                        task_rotator.current.store(Arc::new(new.key));
+                        *state.lock().await = (new.public_key, new.expiration);
+
+                        if let Err(e) = authorizer.shrink_validity(&previous_public_key, config.overlap).await {
+                            on_rotation_failure(format!(
+                                "response key rotated but failed to shrink previous key's validity: {}",
+                                e
+                            ));
+                        }
+                    }
+                    Err(e) => on_rotation_failure(format!("response key rotation failed: {}", e)),
+                }
+            }
+        });
+
+        rotator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex as StdMutex;
+
+    struct FakeAuthorizer {
+        authorize_calls: AtomicUsize,
+        shrink_calls: StdMutex<Vec<(String, ChronoDuration)>>,
+        authorize_should_fail: bool,
+    }
+
+    impl FakeAuthorizer {
+        fn new(authorize_should_fail: bool) -> Self {
+            Self {
+                authorize_calls: AtomicUsize::new(0),
+                shrink_calls: StdMutex::new(Vec::new()),
+                authorize_should_fail,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ResponseKeyAuthorizer for FakeAuthorizer {
+        async fn authorize_new_key(&self) -> Result<AuthorizedKey, String> {
+            let n = self.authorize_calls.fetch_add(1, Ordering::SeqCst) + 1;
+
+            if self.authorize_should_fail {
+                return Err("hsm unreachable".to_string());
+            }
+
+            Ok(AuthorizedKey {
+                key: Secp256r1::new(),
+                public_key: format!("key-{}", n),
+                expiration: Utc::now() + ChronoDuration::hours(6),
+            })
+        }
+
+        async fn shrink_validity(&self, public_key: &str, ttl: ChronoDuration) -> Result<(), String> {
+            self.shrink_calls.lock().unwrap().push((public_key.to_string(), ttl));
+            Ok(())
+        }
+    }
+
+    fn test_config() -> RotationConfig {
+        RotationConfig {
+            rotate_before_expiry: ChronoDuration::hours(1),
+            overlap: ChronoDuration::minutes(5),
+            poll_interval: Duration::from_millis(10),
+        }
+    }
+
+    async fn wait_until(mut condition: impl FnMut() -> bool) {
+        for _ in 0..200 {
+            if condition() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("condition not met in time");
+    }
+
+    #[tokio::test]
+    async fn test_rotates_and_shrinks_previous_key_when_expiry_is_near() {
+        let authorizer = Arc::new(FakeAuthorizer::new(false));
+        let initial = AuthorizedKey {
+            key: Secp256r1::new(),
+            public_key: "initial".to_string(),
+            expiration: Utc::now(),
+        };
+
+        let _rotator = ResponseKeyRotator::spawn(
+            Arc::clone(&authorizer) as Arc<dyn ResponseKeyAuthorizer>,
+            initial,
+            test_config(),
+            |_| {},
+        );
+
+        wait_until(|| !authorizer.shrink_calls.lock().unwrap().is_empty()).await;
+
+        assert!(authorizer.authorize_calls.load(Ordering::SeqCst) >= 1);
+        assert_eq!(
+            *authorizer.shrink_calls.lock().unwrap(),
+            vec![("initial".to_string(), ChronoDuration::minutes(5))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_does_not_rotate_before_the_window() {
+        let authorizer = Arc::new(FakeAuthorizer::new(false));
+        let initial = AuthorizedKey {
+            key: Secp256r1::new(),
+            public_key: "initial".to_string(),
+            expiration: Utc::now() + ChronoDuration::hours(6),
+        };
+
+        let _rotator = ResponseKeyRotator::spawn(
+            Arc::clone(&authorizer) as Arc<dyn ResponseKeyAuthorizer>,
+            initial,
+            test_config(),
+            |_| {},
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(authorizer.authorize_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failure_callback_invoked_and_previous_key_not_shrunk() {
+        let authorizer = Arc::new(FakeAuthorizer::new(true));
+        let initial = AuthorizedKey {
+            key: Secp256r1::new(),
+            public_key: "initial".to_string(),
+            expiration: Utc::now(),
+        };
+
+        let failures: Arc<StdMutex<Vec<String>>> = Arc::new(StdMutex::new(Vec::new()));
+        let failures_clone = Arc::clone(&failures);
+
+        let _rotator = ResponseKeyRotator::spawn(
+            Arc::clone(&authorizer) as Arc<dyn ResponseKeyAuthorizer>,
+            initial,
+            test_config(),
+            move |e| failures_clone.lock().unwrap().push(e),
+        );
+
+        wait_until(|| !failures.lock().unwrap().is_empty()).await;
+
+        assert!(failures.lock().unwrap()[0].contains("response key rotation failed"));
+        assert!(authorizer.shrink_calls.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use base64::Engine;
+use better_auth::interfaces::Verifier;
+use k256::ecdsa::{signature::Verifier as SigVerifier, Signature, VerifyingKey};
+
+/// CESR derivation code for a secp256k1 verification key.
+pub const CESR_CODE: &str = "1AAB";
+
+/// Verifies signatures produced by a secp256k1 signing key, using the same
+/// CESR framing as [`super::Secp256r1Verifier`]: a 3-byte code+pad prefix on
+/// the public key, a 2-byte prefix on the signature, and a raw fixed-length
+/// (r,s) encoding.
+pub struct Secp256k1Verifier;
+
+impl Secp256k1Verifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Secp256k1Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Verifier for Secp256k1Verifier {
+    async fn verify(&self, message: &str, signature: &str, public_key: &str) -> Result<(), String> {
+        let pk_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(public_key)
+            .map_err(|e| format!("Failed to decode public key: {}", e))?;
+        let pk_bytes = &pk_bytes[3..];
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(pk_bytes)
+            .map_err(|e| format!("Failed to import public key: {}", e))?;
+
+        let sig_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(signature)
+            .map_err(|e| format!("Failed to decode signature: {}", e))?;
+        let sig_bytes = &sig_bytes[2..];
+
+        let sig = Signature::try_from(sig_bytes)
+            .map_err(|e| format!("Failed to parse signature: {}", e))?;
+
+        verifying_key
+            .verify(message.as_bytes(), &sig)
+            .map_err(|_| "invalid signature".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::{signature::Signer, SigningKey};
+    use rand::rngs::OsRng;
+
+    fn framed_public_key(verifying_key: &VerifyingKey) -> String {
+        let mut framed = vec![0u8, 0u8, 0u8];
+        framed.extend_from_slice(verifying_key.to_encoded_point(true).as_bytes());
+        base64::engine::general_purpose::URL_SAFE.encode(framed)
+    }
+
+    fn framed_signature(signature: &Signature) -> String {
+        let mut framed = vec![0u8, 0u8];
+        framed.extend_from_slice(&signature.to_bytes());
+        base64::engine::general_purpose::URL_SAFE.encode(framed)
+    }
+
+    #[tokio::test]
+    async fn test_verify_valid_signature() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = "hello from the KEL";
+        let signature: Signature = signing_key.sign(message.as_bytes());
+
+        let public_key = framed_public_key(signing_key.verifying_key());
+        let signature = framed_signature(&signature);
+
+        Secp256k1Verifier::new().verify(message, &signature, &public_key).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_tampered_signature() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = "hello from the KEL";
+        let signature: Signature = signing_key.sign(message.as_bytes());
+
+        let public_key = framed_public_key(signing_key.verifying_key());
+        let signature = framed_signature(&signature);
+
+        assert!(Secp256k1Verifier::new().verify("a different message", &signature, &public_key).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_wrong_key() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let other_key = SigningKey::random(&mut OsRng);
+        let message = "hello from the KEL";
+        let signature: Signature = signing_key.sign(message.as_bytes());
+
+        let public_key = framed_public_key(other_key.verifying_key());
+        let signature = framed_signature(&signature);
+
+        assert!(Secp256k1Verifier::new().verify(message, &signature, &public_key).await.is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use base64::Engine;
+use better_auth::interfaces::{SigningKey, Verifier};
+use p256::ecdsa::signature::{Signer, Verifier as SigVerifier};
+use p256::ecdsa::{Signature, SigningKey as EcdsaSigningKey, VerifyingKey};
+use p256::elliptic_curve::rand_core::OsRng;
+
+use super::encrypted_key::{decrypt_signing_key, encrypt_signing_key, ArgonParams};
+
+/// CESR derivation code for a secp256r1 (P-256) verification key.
+pub const CESR_CODE: &str = "1AAA";
+
+const PUBLIC_KEY_PAD: [u8; 3] = [0xD4, 0x00, 0x00];
+const SIGNATURE_PAD: [u8; 2] = [0, 0];
+
+/// A secp256r1 (P-256, `ES256`) `SigningKey`, framed the same way
+/// [`Secp256r1Verifier`] expects: a 3-byte code+pad prefix on the public
+/// key, a 2-byte prefix on the signature, both ahead of the raw
+/// fixed-length encoding.
+///
+/// Starts out empty; call [`Secp256r1::generate`] (or recover one via
+/// [`Secp256r1::import_encrypted`]) before signing or reading the public
+/// key.
+pub struct Secp256r1 {
+    key: Option<EcdsaSigningKey>,
+}
+
+impl Secp256r1 {
+    pub fn new() -> Self {
+        Self { key: None }
+    }
+
+    /// Generate a fresh random keypair, discarding any key already held.
+    pub fn generate(&mut self) -> Result<(), String> {
+        self.key = Some(EcdsaSigningKey::random(&mut OsRng));
+        Ok(())
+    }
+
+    fn key(&self) -> Result<&EcdsaSigningKey, String> {
+        self.key.as_ref().ok_or_else(|| "signing key not initialized".to_string())
+    }
+
+    /// Encrypt this key's raw bytes at rest with a passphrase, via
+    /// [`encrypt_signing_key`].
+    pub fn export_encrypted(&self, passphrase: &str) -> Result<String, String> {
+        let key = self.key()?;
+        encrypt_signing_key(key.to_bytes().as_slice(), passphrase, &ArgonParams::default())
+    }
+
+    /// Inverse of [`Secp256r1::export_encrypted`]: decrypt `blob` with
+    /// `passphrase` via [`decrypt_signing_key`] and load the recovered key.
+    pub fn import_encrypted(blob: &str, passphrase: &str) -> Result<Self, String> {
+        let key_bytes = decrypt_signing_key(blob, passphrase, &ArgonParams::default())?;
+        let key = EcdsaSigningKey::from_slice(&key_bytes).map_err(|e| format!("failed to import signing key: {}", e))?;
+
+        Ok(Self { key: Some(key) })
+    }
+}
+
+impl Default for Secp256r1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SigningKey for Secp256r1 {
+    async fn identity(&self) -> Result<String, String> {
+        // Non-transferable: the key is its own identifier.
+        self.public().await
+    }
+
+    async fn public(&self) -> Result<String, String> {
+        let key = self.key()?;
+        let verifying_key = VerifyingKey::from(key);
+        let pk_bytes = verifying_key.to_encoded_point(true);
+
+        let mut framed = Vec::with_capacity(PUBLIC_KEY_PAD.len() + pk_bytes.as_bytes().len());
+        framed.extend_from_slice(&PUBLIC_KEY_PAD);
+        framed.extend_from_slice(pk_bytes.as_bytes());
+
+        Ok(base64::engine::general_purpose::URL_SAFE.encode(framed))
+    }
+
+    async fn sign(&self, message: &str) -> Result<String, String> {
+        let key = self.key()?;
+        let signature: Signature = key.sign(message.as_bytes());
+
+        let mut framed = Vec::with_capacity(SIGNATURE_PAD.len() + signature.to_bytes().len());
+        framed.extend_from_slice(&SIGNATURE_PAD);
+        framed.extend_from_slice(&signature.to_bytes());
+
+        Ok(base64::engine::general_purpose::URL_SAFE.encode(framed))
+    }
+}
+
+/// Verifies signatures produced by [`Secp256r1`], using the same CESR
+/// framing as [`super::Secp256k1Verifier`]: a 3-byte code+pad prefix on
+/// the public key, a 2-byte prefix on the signature, and a raw
+/// fixed-length (r,s) encoding.
+pub struct Secp256r1Verifier;
+
+impl Secp256r1Verifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Secp256r1Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Verifier for Secp256r1Verifier {
+    async fn verify(&self, message: &str, signature: &str, public_key: &str) -> Result<(), String> {
+        let pk_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(public_key)
+            .map_err(|e| format!("Failed to decode public key: {}", e))?;
+        let pk_bytes = &pk_bytes[3..];
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(pk_bytes)
+            .map_err(|e| format!("Failed to import public key: {}", e))?;
+
+        let sig_bytes = base64::engine::general_purpose::URL_SAFE
+            .decode(signature)
+            .map_err(|e| format!("Failed to decode signature: {}", e))?;
+        let sig_bytes = &sig_bytes[2..];
+
+        let sig = Signature::try_from(sig_bytes)
+            .map_err(|e| format!("Failed to parse signature: {}", e))?;
+
+        verifying_key
+            .verify(message.as_bytes(), &sig)
+            .map_err(|_| "invalid signature".to_string())
+    }
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use better_auth::interfaces::Verifier;
+
+use super::ed25519_verifier::{Ed25519Verifier, CESR_CODE as ED25519_CODE};
+use super::secp256k1_verifier::{Secp256k1Verifier, CESR_CODE as SECP256K1_CODE};
+use super::secp256r1::Secp256r1Verifier;
+
+/// CESR derivation code for a secp256r1 (P-256) verification key, matching
+/// the prefix `Secp256r1Verifier` already expects.
+const SECP256R1_CODE: &str = "1AAA";
+
+/// Dispatches signature verification to the curve implementation matching
+/// the CESR derivation code embedded in the public key, so a single KEL can
+/// mix key types across rotations without the caller knowing the curve in
+/// advance.
+pub struct VerifierRegistry;
+
+impl VerifierRegistry {
+    pub async fn verify(message: &str, signature: &str, public_key: &str) -> Result<(), String> {
+        if public_key.starts_with(SECP256R1_CODE) {
+            return Secp256r1Verifier::new().verify(message, signature, public_key).await;
+        }
+
+        if public_key.starts_with(SECP256K1_CODE) {
+            return Secp256k1Verifier::new().verify(message, signature, public_key).await;
+        }
+
+        if public_key.starts_with(ED25519_CODE) {
+            return Ed25519Verifier::new().verify(message, signature, public_key).await;
+        }
+
+        Err(format!(
+            "unsupported verification key derivation code in {}",
+            &public_key[..public_key.len().min(4)]
+        ))
+    }
+}
+
+/// Lets a `VerifierRegistry` be handed out as a `&dyn Verifier` (e.g. from
+/// `VerificationKey::verifier`), delegating to the dispatch above.
+#[async_trait]
+impl Verifier for VerifierRegistry {
+    async fn verify(&self, message: &str, signature: &str, public_key: &str) -> Result<(), String> {
+        Self::verify(message, signature, public_key).await
+    }
+}
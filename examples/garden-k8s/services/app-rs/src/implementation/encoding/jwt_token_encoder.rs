@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use base64::Engine;
+use better_auth::interfaces::{SigningKey, TokenEncoder as TokenEncoderTrait, VerificationKey};
+
+const JWT_HEADER: &str = r#"{"alg":"ES256","typ":"JWT"}"#;
+
+/// `SigningKey`/`Verifier` in this crate frame signatures as CESR: a 2-byte
+/// code+pad prefix followed by the raw fixed-length (r,s) bytes (see
+/// `crypto::secp256k1_verifier`/`crypto::ed25519_verifier`). Neither prefix
+/// byte is ever inspected by a verifier, only skipped, so any 2 bytes here
+/// round-trip correctly.
+const CESR_SIGNATURE_PAD: [u8; 2] = [0, 0];
+
+/// Strips the CESR code+pad prefix from a signature produced by
+/// `SigningKey::sign`, leaving the raw (r,s) bytes a standard JWS signature
+/// segment requires.
+fn strip_cesr_signature(cesr_signature: &str) -> Result<Vec<u8>, String> {
+    let bytes = base64::engine::general_purpose::URL_SAFE
+        .decode(cesr_signature)
+        .map_err(|e| format!("failed to decode CESR signature: {}", e))?;
+
+    if bytes.len() <= CESR_SIGNATURE_PAD.len() {
+        return Err("CESR signature too short".to_string());
+    }
+
+    Ok(bytes[CESR_SIGNATURE_PAD.len()..].to_vec())
+}
+
+/// Re-frames raw (r,s) signature bytes decoded from a JWS signature segment
+/// back into the CESR encoding this crate's verifiers expect.
+fn wrap_cesr_signature(raw_signature: &[u8]) -> String {
+    let mut framed = Vec::with_capacity(CESR_SIGNATURE_PAD.len() + raw_signature.len());
+    framed.extend_from_slice(&CESR_SIGNATURE_PAD);
+    framed.extend_from_slice(raw_signature);
+
+    base64::engine::general_purpose::URL_SAFE.encode(framed)
+}
+
+/// Encodes/decodes tokens as standard compact JWS (`header.payload.signature`,
+/// base64url), so an `AccessToken<T>` round-trips as an ordinary signed JWT
+/// consumable by off-the-shelf JWT middleware, instead of this crate's
+/// bespoke framing. `payload` is already the serialized token JSON produced
+/// upstream, so this encoder only owns the JWT framing and signature, not
+/// the claims themselves.
+pub struct JwtTokenEncoder;
+
+impl JwtTokenEncoder {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JwtTokenEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TokenEncoderTrait for JwtTokenEncoder {
+    async fn encode(&self, payload: &str, signing_key: &dyn SigningKey) -> Result<String, String> {
+        let header_b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(JWT_HEADER);
+        let payload_b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload);
+        let signing_input = format!("{}.{}", header_b64, payload_b64);
+
+        let cesr_signature = signing_key.sign(&signing_input).await?;
+        let raw_signature = strip_cesr_signature(&cesr_signature)?;
+        let signature_b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(raw_signature);
+
+        Ok(format!("{}.{}", signing_input, signature_b64))
+    }
+
+    async fn decode(&self, token: &str, verification_key: &dyn VerificationKey) -> Result<String, String> {
+        let mut parts = token.split('.');
+        let header_b64 = parts.next().ok_or("missing JWT header")?;
+        let payload_b64 = parts.next().ok_or("missing JWT payload")?;
+        let signature_b64 = parts.next().ok_or("missing JWT signature")?;
+
+        if parts.next().is_some() {
+            return Err("malformed JWT: too many segments".to_string());
+        }
+
+        let signing_input = format!("{}.{}", header_b64, payload_b64);
+
+        let raw_signature = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(signature_b64)
+            .map_err(|e| format!("failed to decode JWT signature: {}", e))?;
+        let cesr_signature = wrap_cesr_signature(&raw_signature);
+
+        let public_key = verification_key.public().await?;
+        verification_key
+            .verifier()
+            .verify(&signing_input, &cesr_signature, &public_key)
+            .await?;
+
+        let payload_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload_b64)
+            .map_err(|e| format!("failed to decode JWT payload: {}", e))?;
+
+        String::from_utf8(payload_bytes).map_err(|e| format!("invalid JWT payload encoding: {}", e))
+    }
+}
@@ -0,0 +1,9 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod jwt_token_encoder;
+pub mod timestamper;
+pub mod token_encoder;
+
+pub use jwt_token_encoder::*;
+pub use timestamper::*;
+pub use token_encoder::*;
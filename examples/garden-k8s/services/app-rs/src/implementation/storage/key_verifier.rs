@@ -1,17 +1,20 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
+use futures_util::StreamExt;
+use lru::LruCache;
 use redis::aio::ConnectionManager;
 use redis::{AsyncCommands, RedisError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use better_auth::interfaces::Verifier;
-use super::super::crypto::{Blake3Hasher, Secp256r1Verifier};
+use super::super::crypto::{CesrHasher, VerifierRegistry};
 use super::utils::get_sub_json;
 
-const HSM_IDENTITY: &str = "BETTER_AUTH_HSM_IDENTITY_PLACEHOLDER";
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
+const DEFAULT_SCAN_BATCH_SIZE: usize = 256;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,19 +43,126 @@ struct ExpiringEntry {
 
 pub struct KeyVerifier {
     connection: Arc<Mutex<ConnectionManager>>,
-    cache: Arc<Mutex<HashMap<String, ExpiringEntry>>>,
+    cache: Arc<Mutex<LruCache<String, ExpiringEntry>>>,
     verification_window_seconds: i64,
+    key_prefix: String,
+    scan_batch_size: usize,
 }
 
 impl KeyVerifier {
     pub fn new(connection: ConnectionManager, server_lifetime_hours: i64, access_lifetime_minutes: i64) -> Self {
+        Self::with_cache_capacity(connection, server_lifetime_hours, access_lifetime_minutes, DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Like [`KeyVerifier::new`], but with an explicit bound on the number of
+    /// HSM generations kept in the LRU cache.
+    pub fn with_cache_capacity(
+        connection: ConnectionManager,
+        server_lifetime_hours: i64,
+        access_lifetime_minutes: i64,
+        cache_capacity: usize,
+    ) -> Self {
+        Self::with_options(
+            connection,
+            server_lifetime_hours,
+            access_lifetime_minutes,
+            cache_capacity,
+            String::new(),
+            DEFAULT_SCAN_BATCH_SIZE,
+        )
+    }
+
+    /// Like [`KeyVerifier::with_cache_capacity`], but additionally scoped to
+    /// HSM log keys under `key_prefix` and with an explicit `SCAN` batch
+    /// size, instead of loading the entire Redis keyspace on a cache miss.
+    pub fn with_options(
+        connection: ConnectionManager,
+        server_lifetime_hours: i64,
+        access_lifetime_minutes: i64,
+        cache_capacity: usize,
+        key_prefix: impl Into<String>,
+        scan_batch_size: usize,
+    ) -> Self {
+        let capacity = NonZeroUsize::new(cache_capacity).unwrap_or(NonZeroUsize::new(DEFAULT_CACHE_CAPACITY).unwrap());
+
         Self {
             connection: Arc::new(Mutex::new(connection)),
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(LruCache::new(capacity))),
             verification_window_seconds: server_lifetime_hours * 3600 + access_lifetime_minutes * 60,
+            key_prefix: key_prefix.into(),
+            scan_batch_size: scan_batch_size.max(1),
         }
     }
 
+    /// Subscribe to `channel` for HSM log update notifications and evict the
+    /// published generation id from the cache as soon as it arrives, instead
+    /// of waiting for the next miss to discover it's stale.
+    ///
+    /// Spawns a background task tied to its own pub/sub connection; failures
+    /// to connect or subscribe are logged and the task exits, leaving the
+    /// miss-triggered refresh as the fallback invalidation path.
+    pub fn spawn_invalidation_listener(&self, client: redis::Client, channel: String) {
+        let cache = Arc::clone(&self.cache);
+
+        tokio::spawn(async move {
+            let conn = match client.get_async_connection().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    eprintln!("Warning: failed to open HSM invalidation pubsub connection: {}", e);
+                    return;
+                }
+            };
+
+            let mut pubsub = conn.into_pubsub();
+            if let Err(e) = pubsub.subscribe(&channel).await {
+                eprintln!("Warning: failed to subscribe to {}: {}", channel, e);
+                return;
+            }
+
+            let mut messages = pubsub.on_message();
+            while let Some(message) = messages.next().await {
+                if let Ok(generation_id) = message.get_payload::<String>() {
+                    cache.lock().await.pop(&generation_id);
+                }
+            }
+        });
+    }
+
+    /// Load every HSM log record under `key_prefix` via `SCAN`/`MATCH`,
+    /// pipelining an `MGET` per batch instead of blocking Redis with a
+    /// single `KEYS *` over the whole keyspace.
+    async fn scan_all(&self) -> Result<Vec<String>, String> {
+        let mut conn = self.connection.lock().await;
+        let pattern = format!("{}*", self.key_prefix);
+        let mut cursor: u64 = 0;
+        let mut values = Vec::new();
+
+        loop {
+            let (next_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(self.scan_batch_size)
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| format!("Redis scan error: {}", e))?;
+
+            if !keys.is_empty() {
+                let batch: Vec<Option<String>> = conn.mget(&keys).await
+                    .map_err(|e| format!("Redis mget error: {}", e))?;
+                values.extend(batch.into_iter().flatten());
+            }
+
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        Ok(values)
+    }
+
     pub async fn verify(
         &self,
         signature: &str,
@@ -60,37 +170,36 @@ impl KeyVerifier {
         hsm_generation_id: &str,
         message: &str,
     ) -> Result<(), String> {
-        let cache = self.cache.lock().await;
+        let mut cache = self.cache.lock().await;
 
-        if let Some(cached_entry) = cache.get(hsm_generation_id) {
-            return self.verify_with_entry(cached_entry, signature, hsm_identity, message).await;
-        }
+        let is_fresh = cache
+            .peek(hsm_generation_id)
+            .map(|e| e.expiration.map(|exp| exp >= Utc::now()).unwrap_or(true))
+            .unwrap_or(false);
 
-        drop(cache);
+        if is_fresh {
+            if let Some(cached_entry) = cache.get(hsm_generation_id) {
+                return self.verify_with_entry(cached_entry, signature, hsm_identity, message).await;
+            }
+        } else {
+            // An expired entry only invalidates itself, not the whole cache.
+            cache.pop(hsm_generation_id);
+        }
 
-        // Clear cache before repopulating
-        let mut cache = self.cache.lock().await;
-        cache.clear();
         drop(cache);
 
-        // Fetch all HSM keys from Redis
-        let mut conn = self.connection.lock().await;
-        let keys: Vec<String> = conn.keys("*").await
-            .map_err(|e| format!("Redis keys error: {}", e))?;
+        // Stream the HSM log in from Redis via SCAN, batch by batch, rather
+        // than blocking on a full-keyspace KEYS * + MGET.
+        let values = self.scan_all().await?;
 
-        if keys.is_empty() {
+        if values.is_empty() {
             return Err("No HSM keys found in Redis".to_string());
         }
 
-        let values: Vec<Option<String>> = conn.mget(&keys).await
-            .map_err(|e| format!("Redis mget error: {}", e))?;
-
-        drop(conn);
-
         // Group by prefix
         let mut by_prefix: HashMap<String, Vec<(SignedLogEntry, String)>> = HashMap::new();
 
-        for value in values.into_iter().flatten() {
+        for value in values.into_iter() {
             let payload_json = get_sub_json(&value, "payload")?;
             let record: SignedLogEntry = serde_json::from_str(&value)
                 .map_err(|e| format!("Failed to parse HSM record: {}", e))?;
@@ -117,8 +226,7 @@ impl KeyVerifier {
                 }
 
                 // Verify signature over payload using the extracted JSON string
-                let verifier = Secp256r1Verifier::new();
-                verifier.verify(payload_json, &record.signature, &payload.public_key).await?;
+                VerifierRegistry::verify(payload_json, &record.signature, &payload.public_key).await?;
             }
         }
 
@@ -155,8 +263,8 @@ impl KeyVerifier {
                         return Err("non-increasing timestamp".to_string());
                     }
 
-                    let hasher = Blake3Hasher::new();
-                    let hash = hasher.sum(&payload.public_key);
+                    let algorithm = CesrHasher::from_cesr(&last_rotation_hash)?;
+                    let hash = algorithm.sum(&payload.public_key);
 
                     if hash != last_rotation_hash {
                         return Err("bad commitment".to_string());
@@ -170,7 +278,7 @@ impl KeyVerifier {
         }
 
         // Verify prefix exists
-        let records = by_prefix.get(HSM_IDENTITY)
+        let records = by_prefix.get(hsm_identity)
             .ok_or("hsm identity not found".to_string())?;
 
         // Cache entries within 12-hour window (iterate backwards)
@@ -181,7 +289,7 @@ impl KeyVerifier {
             let payload = &record.payload;
 
             if !tainted {
-                cache.insert(payload.id.clone(), ExpiringEntry {
+                cache.put(payload.id.clone(), ExpiringEntry {
                     entry: payload.clone(),
                     expiration,
                 });
@@ -228,9 +336,9 @@ impl KeyVerifier {
             }
         }
 
-        // Verify message signature
-        let verifier = Secp256r1Verifier::new();
-        verifier.verify(message, signature, &cached_entry.entry.public_key).await
+        // Verify message signature, dispatching on the key's own CESR code
+        // so a single KEL can mix curves across rotations.
+        VerifierRegistry::verify(message, signature, &cached_entry.entry.public_key).await
     }
 
     async fn verify_prefix_and_data(payload_json: &str, payload: &LogEntry) -> Result<(), String> {
@@ -244,8 +352,8 @@ impl KeyVerifier {
     async fn verify_address_and_data(payload_json: &str, payload: &LogEntry) -> Result<(), String> {
         let modified_payload = payload_json.replace(&payload.id, "############################################");
 
-        let hasher = Blake3Hasher::new();
-        let hash = hasher.sum(&modified_payload);
+        let algorithm = CesrHasher::from_cesr(&payload.id)?;
+        let hash = algorithm.sum(&modified_payload);
 
         if hash != payload.id {
             return Err("id does not match hash of payload".to_string());
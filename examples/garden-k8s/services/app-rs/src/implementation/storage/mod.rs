@@ -0,0 +1,17 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod canonical_json;
+pub mod key_verifier;
+pub mod redis_server_time_lock_store;
+pub mod redis_verification_key_store;
+pub mod revocation_store;
+pub mod server_time_lock_store;
+pub mod utils;
+
+pub use canonical_json::*;
+pub use key_verifier::*;
+pub use redis_server_time_lock_store::*;
+pub use redis_verification_key_store::*;
+pub use revocation_store::*;
+pub use server_time_lock_store::*;
+pub use utils::*;
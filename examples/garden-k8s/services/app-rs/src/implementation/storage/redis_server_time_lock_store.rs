@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use better_auth::interfaces::ServerTimeLockStore as ServerTimeLockStoreTrait;
+use redis::aio::ConnectionManager;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Redis-backed `ServerTimeLockStore` that reserves nonces with an atomic
+/// `SET key value NX PX <lifetime_ms>`, so the check-and-insert can't race
+/// across instances behind a load balancer the way the in-process
+/// `ServerTimeLockStore` would. Reservations also survive a server
+/// restart, since they live in Redis rather than process memory.
+///
+/// Holds a single `Arc<Mutex<ConnectionManager>>`; the underlying
+/// `ConnectionManager` reconnects on its own after a transient failure.
+pub struct RedisServerTimeLockStore {
+    connection: Arc<Mutex<ConnectionManager>>,
+    lifetime_in_seconds: u64,
+    key_prefix: String,
+}
+
+impl RedisServerTimeLockStore {
+    pub fn new(connection: ConnectionManager, lifetime_in_seconds: u64) -> Self {
+        Self::with_key_prefix(connection, lifetime_in_seconds, String::new())
+    }
+
+    /// Like [`RedisServerTimeLockStore::new`], but scoping reserved nonces
+    /// under `key_prefix` so several deployments can share one Redis
+    /// logical DB.
+    pub fn with_key_prefix(connection: ConnectionManager, lifetime_in_seconds: u64, key_prefix: impl Into<String>) -> Self {
+        Self {
+            connection: Arc::new(Mutex::new(connection)),
+            lifetime_in_seconds,
+            key_prefix: key_prefix.into(),
+        }
+    }
+
+    fn namespaced(&self, value: &str) -> String {
+        format!("{}{}", self.key_prefix, value)
+    }
+}
+
+#[async_trait]
+impl ServerTimeLockStoreTrait for RedisServerTimeLockStore {
+    fn lifetime_in_seconds(&self) -> u64 {
+        self.lifetime_in_seconds
+    }
+
+    async fn reserve(&self, value: String) -> Result<(), String> {
+        let lifetime_ms = self.lifetime_in_seconds * 1000;
+
+        let mut conn = self.connection.lock().await;
+        let reserved: Option<String> = redis::cmd("SET")
+            .arg(self.namespaced(&value))
+            .arg("1")
+            .arg("NX")
+            .arg("PX")
+            .arg(lifetime_ms)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| format!("Redis error: {}", e))?;
+
+        match reserved {
+            Some(_) => Ok(()),
+            None => Err("value reserved too recently".to_string()),
+        }
+    }
+}
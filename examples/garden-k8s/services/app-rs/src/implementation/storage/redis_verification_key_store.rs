@@ -0,0 +1,227 @@
+use super::key_verifier::KeyVerifier;
+use super::revocation_store::RevocationStore;
+use super::utils::get_sub_json;
+use crate::implementation::crypto::VerifierRegistry;
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use better_auth::interfaces::{VerificationKey, VerificationKeyStore as VerificationKeyStoreTrait};
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const DEFAULT_QUORUM_THRESHOLD: usize = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeySigningPayload {
+    purpose: String,
+    #[serde(rename = "publicKey")]
+    public_key: String,
+    expiration: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeySigningHsmSignature {
+    identity: String,
+    #[serde(rename = "generationId")]
+    generation_id: String,
+    signature: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeySigningBody {
+    payload: KeySigningPayload,
+    hsms: Vec<KeySigningHsmSignature>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeySigningResponse {
+    body: KeySigningBody,
+}
+
+/// Redis-based VerificationKeyStore that checks a connection out of a `bb8`
+/// pool per lookup, instead of serializing every call through one shared
+/// `ConnectionManager`.
+///
+/// Reads are scoped under an optional `key_prefix` namespace (e.g.
+/// `ba:access:`), so several deployments can share one Redis logical DB
+/// without colliding.
+pub struct RedisVerificationKeyStore {
+    pool: Pool<RedisConnectionManager>,
+    key_verifier: Arc<KeyVerifier>,
+    key_prefix: String,
+    quorum_threshold: usize,
+    trusted_hsm_identities: HashSet<String>,
+    revocation_store: Option<Arc<dyn RevocationStore>>,
+}
+
+impl RedisVerificationKeyStore {
+    /// `access_redis_url` backs the connection pool; `hsm_connection` is
+    /// handed off to the `KeyVerifier`, which still does its own HSM-log
+    /// scans over a single `ConnectionManager`. Accepts any single valid
+    /// HSM co-signature, matching the pre-quorum default.
+    pub async fn new(
+        access_redis_url: &str,
+        hsm_connection: redis::aio::ConnectionManager,
+        server_lifetime_hours: i64,
+        access_lifetime_minutes: i64,
+        pool_size: u32,
+        key_prefix: impl Into<String>,
+    ) -> Result<Self, String> {
+        Self::with_quorum(
+            access_redis_url,
+            hsm_connection,
+            server_lifetime_hours,
+            access_lifetime_minutes,
+            pool_size,
+            key_prefix,
+            DEFAULT_QUORUM_THRESHOLD,
+            Vec::new(),
+        )
+        .await
+    }
+
+    /// Like [`RedisVerificationKeyStore::new`], but requiring at least
+    /// `quorum_threshold` distinct HSMs from `trusted_hsm_identities` to
+    /// each produce a valid co-signature over the key's body before it is
+    /// accepted. An empty `trusted_hsm_identities` trusts any identity the
+    /// `KeyVerifier` can independently validate, so `quorum_threshold: 1`
+    /// with an empty set reproduces the single-signer default.
+    pub async fn with_quorum(
+        access_redis_url: &str,
+        hsm_connection: redis::aio::ConnectionManager,
+        server_lifetime_hours: i64,
+        access_lifetime_minutes: i64,
+        pool_size: u32,
+        key_prefix: impl Into<String>,
+        quorum_threshold: usize,
+        trusted_hsm_identities: Vec<String>,
+    ) -> Result<Self, String> {
+        let manager = RedisConnectionManager::new(access_redis_url)
+            .map_err(|e| format!("failed to create Redis pool manager: {}", e))?;
+        let pool = Pool::builder()
+            .max_size(pool_size)
+            .build(manager)
+            .await
+            .map_err(|e| format!("failed to build Redis connection pool: {}", e))?;
+
+        Ok(Self {
+            pool,
+            key_verifier: Arc::new(KeyVerifier::new(hsm_connection, server_lifetime_hours, access_lifetime_minutes)),
+            key_prefix: key_prefix.into(),
+            quorum_threshold: quorum_threshold.max(1),
+            trusted_hsm_identities: trusted_hsm_identities.into_iter().collect(),
+            revocation_store: None,
+        })
+    }
+
+    /// Rejects a lookup for a revoked device's identity before the key (and
+    /// therefore the token it would verify) is ever returned, instead of
+    /// leaving revocation as a check callers have to remember to run after
+    /// `AccessVerifier::verify` succeeds.
+    pub fn with_revocation_store(mut self, revocation_store: Arc<dyn RevocationStore>) -> Self {
+        self.revocation_store = Some(revocation_store);
+        self
+    }
+
+    fn namespaced(&self, identity: &str) -> String {
+        format!("{}{}", self.key_prefix, identity)
+    }
+
+    fn is_trusted(&self, identity: &str) -> bool {
+        self.trusted_hsm_identities.is_empty() || self.trusted_hsm_identities.contains(identity)
+    }
+}
+
+#[async_trait]
+impl VerificationKeyStoreTrait for RedisVerificationKeyStore {
+    async fn get(&self, identity: &str) -> Result<Box<dyn VerificationKey>, String> {
+        if let Some(revocation_store) = &self.revocation_store {
+            if revocation_store.is_revoked(identity).await? {
+                return Err(format!("device revoked: {}", identity));
+            }
+        }
+
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| format!("failed to check out Redis connection: {}", e))?;
+
+        let value: String = conn
+            .get(self.namespaced(identity))
+            .await
+            .map_err(|e| format!("Redis error: {}", e))?;
+
+        self.process_response(&value).await
+    }
+}
+
+impl RedisVerificationKeyStore {
+    async fn process_response(&self, value: &str) -> Result<Box<dyn VerificationKey>, String> {
+        let response: KeySigningResponse =
+            serde_json::from_str(value).map_err(|e| format!("failed to parse response: {}", e))?;
+
+        // Each HSM co-signs only `payload`, never `body` (which holds every
+        // HSM's own signature, including its own) — an honest signer can't
+        // sign over text that already contains the signature being produced.
+        let payload_json = get_sub_json(value, "payload")?;
+
+        let mut verified_identities = HashSet::new();
+        for hsm in &response.body.hsms {
+            if !self.is_trusted(&hsm.identity) || verified_identities.contains(&hsm.identity) {
+                continue;
+            }
+
+            if self
+                .key_verifier
+                .verify(&hsm.signature, &hsm.identity, &hsm.generation_id, &payload_json)
+                .await
+                .is_ok()
+            {
+                verified_identities.insert(hsm.identity.clone());
+            }
+        }
+
+        if verified_identities.len() < self.quorum_threshold {
+            return Err(format!(
+                "quorum not met: {} of {} required HSM signatures valid",
+                verified_identities.len(),
+                self.quorum_threshold
+            ));
+        }
+
+        if response.body.payload.purpose != "access" {
+            return Err(format!("invalid purpose: expected access, got {}", response.body.payload.purpose));
+        }
+
+        let expiration = chrono::DateTime::parse_from_rfc3339(&response.body.payload.expiration)
+            .map_err(|e| format!("failed to parse expiration: {}", e))?;
+        if expiration <= chrono::Utc::now() {
+            return Err("key expired".to_string());
+        }
+
+        Ok(Box::new(PublicKeyWrapper {
+            public_key: response.body.payload.public_key,
+        }) as Box<dyn VerificationKey>)
+    }
+}
+
+/// Wrapper for a public key string that implements VerificationKey,
+/// dispatching verification through `VerifierRegistry` so it isn't pinned
+/// to a single curve.
+struct PublicKeyWrapper {
+    public_key: String,
+}
+
+#[async_trait]
+impl VerificationKey for PublicKeyWrapper {
+    async fn public(&self) -> Result<String, String> {
+        Ok(self.public_key.clone())
+    }
+
+    fn verifier(&self) -> &dyn better_auth::interfaces::Verifier {
+        &VerifierRegistry
+    }
+}
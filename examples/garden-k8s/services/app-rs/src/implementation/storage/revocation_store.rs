@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use futures_util::StreamExt;
+use redis::AsyncCommands;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+const DEFAULT_SCAN_BATCH_SIZE: usize = 256;
+
+/// Mirrors the `revocation` slot a first-class `AccessVerifierStore` would
+/// consult automatically. Until that lands upstream, `RedisVerificationKeyStore`
+/// wires this in on the `get()` path instead: a revoked device's key lookup
+/// fails outright, so `AccessVerifier::verify` itself rejects it rather than
+/// a caller having to check separately after a token decodes successfully.
+#[async_trait]
+pub trait RevocationStore: Send + Sync {
+    async fn is_revoked(&self, device: &str) -> Result<bool, String>;
+}
+
+/// Redis-backed `RevocationStore` that keeps revoked device ids in an
+/// in-process set, kept current two ways: a `revoked-devices` pub/sub
+/// subscription for new revocations, and a `SCAN`-based [`Self::sync`]
+/// reconciliation (call once at startup, and optionally on a timer via
+/// [`Self::spawn_periodic_sync`]) that loads every currently-revoked id.
+///
+/// Until the first successful `sync`, a device absent from the set is
+/// *unknown* rather than *not revoked* (pub/sub only ever hears about new
+/// revocations, never the ones already in effect before the subscriber
+/// connected), so `is_revoked` still falls back to a pooled Redis lookup on
+/// every miss. After `sync`, a miss is trustworthy and the hot path for the
+/// common non-revoked case no longer round-trips to Redis at all.
+pub struct RedisRevocationStore {
+    pool: Pool<RedisConnectionManager>,
+    revoked: Arc<Mutex<HashSet<String>>>,
+    key_prefix: String,
+    scan_batch_size: usize,
+    synced: Arc<AtomicBool>,
+}
+
+impl RedisRevocationStore {
+    pub async fn new(redis_url: &str, pool_size: u32, key_prefix: impl Into<String>) -> Result<Self, String> {
+        let manager = RedisConnectionManager::new(redis_url)
+            .map_err(|e| format!("failed to create Redis pool manager: {}", e))?;
+        let pool = Pool::builder()
+            .max_size(pool_size)
+            .build(manager)
+            .await
+            .map_err(|e| format!("failed to build Redis connection pool: {}", e))?;
+
+        Ok(Self {
+            pool,
+            revoked: Arc::new(Mutex::new(HashSet::new())),
+            key_prefix: key_prefix.into(),
+            scan_batch_size: DEFAULT_SCAN_BATCH_SIZE,
+            synced: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    fn namespaced(&self, device: &str) -> String {
+        format!("{}{}", self.key_prefix, device)
+    }
+
+    /// Subscribe to `channel` for newly-revoked device ids and add each one
+    /// to the in-process set as soon as it's published, so a revocation is
+    /// reflected locally immediately instead of waiting for the next sync.
+    pub fn spawn_subscriber(&self, client: redis::Client, channel: String) {
+        let revoked = Arc::clone(&self.revoked);
+
+        tokio::spawn(async move {
+            let conn = match client.get_async_connection().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    eprintln!("Warning: failed to open revocation pubsub connection: {}", e);
+                    return;
+                }
+            };
+
+            let mut pubsub = conn.into_pubsub();
+            if let Err(e) = pubsub.subscribe(&channel).await {
+                eprintln!("Warning: failed to subscribe to {}: {}", channel, e);
+                return;
+            }
+
+            let mut messages = pubsub.on_message();
+            while let Some(message) = messages.next().await {
+                if let Ok(device) = message.get_payload::<String>() {
+                    revoked.lock().await.insert(device);
+                }
+            }
+        });
+    }
+
+    /// Load every currently-revoked device id under `key_prefix` via `SCAN`
+    /// and merge it into the in-process set, then mark the set as fully
+    /// reconciled so `is_revoked` can trust a miss without a Redis round
+    /// trip. Only ever adds ids, never removes any, so it's safe to call
+    /// concurrently with `spawn_subscriber`'s pub/sub updates.
+    ///
+    /// Call once at startup before serving traffic, and optionally again on
+    /// a timer via [`Self::spawn_periodic_sync`] to pick up revocations
+    /// written directly to Redis outside the pub/sub channel.
+    pub async fn sync(&self) -> Result<(), String> {
+        let ids = Self::scan_revoked(&self.pool, &self.key_prefix, self.scan_batch_size).await?;
+        self.revoked.lock().await.extend(ids);
+        self.synced.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Spawn a background task that calls [`Self::sync`] every `interval`,
+    /// so a sync failure at startup (or a revocation written outside the
+    /// pub/sub channel) is eventually picked up instead of leaving
+    /// `is_revoked` stuck falling back to Redis indefinitely.
+    pub fn spawn_periodic_sync(&self, interval: Duration) {
+        let pool = self.pool.clone();
+        let key_prefix = self.key_prefix.clone();
+        let scan_batch_size = self.scan_batch_size;
+        let revoked = Arc::clone(&self.revoked);
+        let synced = Arc::clone(&self.synced);
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+
+                match Self::scan_revoked(&pool, &key_prefix, scan_batch_size).await {
+                    Ok(ids) => {
+                        revoked.lock().await.extend(ids);
+                        synced.store(true, Ordering::SeqCst);
+                    }
+                    Err(e) => eprintln!("Warning: revocation list sync failed: {}", e),
+                }
+            }
+        });
+    }
+
+    async fn scan_revoked(
+        pool: &Pool<RedisConnectionManager>,
+        key_prefix: &str,
+        scan_batch_size: usize,
+    ) -> Result<HashSet<String>, String> {
+        let mut conn = pool
+            .get()
+            .await
+            .map_err(|e| format!("failed to check out Redis connection: {}", e))?;
+        let pattern = format!("{}*", key_prefix);
+        let mut cursor: u64 = 0;
+        let mut ids = HashSet::new();
+
+        loop {
+            let (next_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(scan_batch_size)
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| format!("Redis scan error: {}", e))?;
+
+            ids.extend(keys.into_iter().map(|k| k.trim_start_matches(key_prefix).to_string()));
+
+            cursor = next_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        Ok(ids)
+    }
+}
+
+#[async_trait]
+impl RevocationStore for RedisRevocationStore {
+    async fn is_revoked(&self, device: &str) -> Result<bool, String> {
+        {
+            let revoked = self.revoked.lock().await;
+            if revoked.contains(device) {
+                return Ok(true);
+            }
+
+            if self.synced.load(Ordering::SeqCst) {
+                return Ok(false);
+            }
+        }
+
+        // Not yet reconciled: a miss here could just mean sync hasn't run,
+        // not that the device is clear, so fall back to Redis.
+        let mut conn = self
+            .pool
+            .get()
+            .await
+            .map_err(|e| format!("failed to check out Redis connection: {}", e))?;
+        let exists: bool = conn
+            .exists(self.namespaced(device))
+            .await
+            .map_err(|e| format!("Failed to check revoked devices: {}", e))?;
+        drop(conn);
+
+        if exists {
+            self.revoked.lock().await.insert(device.to_string());
+        }
+
+        Ok(exists)
+    }
+}
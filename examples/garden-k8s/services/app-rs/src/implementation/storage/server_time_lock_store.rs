@@ -2,9 +2,11 @@ use async_trait::async_trait;
 use better_auth::interfaces::ServerTimeLockStore as ServerTimeLockStoreTrait;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use tokio::sync::Mutex;
 
+const DEFAULT_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
 #[derive(Clone)]
 pub struct ServerTimeLockStore {
     nonces: Arc<Mutex<HashMap<String, SystemTime>>>,
@@ -12,14 +14,125 @@ pub struct ServerTimeLockStore {
 }
 
 impl ServerTimeLockStore {
+    /// Sweeps expired nonces every [`DEFAULT_SWEEP_INTERVAL`] with no hard
+    /// cap on map size. See [`ServerTimeLockStore::with_options`] to
+    /// configure either.
     pub fn new(lifetime_in_seconds: u64) -> Self {
-        Self {
+        Self::with_options(lifetime_in_seconds, DEFAULT_SWEEP_INTERVAL, None)
+    }
+
+    /// Like [`ServerTimeLockStore::new`], but spawns the background sweep
+    /// on `sweep_interval` instead of the default, and enforces `max_size`
+    /// if given: once the map exceeds it, the soonest-to-expire entries are
+    /// evicted first, since they're already closest to no longer being able
+    /// to cause a replay rejection.
+    pub fn with_options(lifetime_in_seconds: u64, sweep_interval: Duration, max_size: Option<usize>) -> Self {
+        let store = Self {
             nonces: Arc::new(Mutex::new(HashMap::new())),
             lifetime_in_seconds,
+        };
+
+        store.spawn_sweeper(sweep_interval, max_size);
+        store
+    }
+
+    fn spawn_sweeper(&self, sweep_interval: Duration, max_size: Option<usize>) {
+        let nonces = Arc::clone(&self.nonces);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(sweep_interval);
+            loop {
+                interval.tick().await;
+                Self::sweep(&nonces, max_size).await;
+            }
+        });
+    }
+
+    async fn sweep(nonces: &Arc<Mutex<HashMap<String, SystemTime>>>, max_size: Option<usize>) {
+        let mut nonces = nonces.lock().await;
+        let now = SystemTime::now();
+        nonces.retain(|_, valid_at| *valid_at >= now);
+
+        let Some(max_size) = max_size else { return };
+        if nonces.len() <= max_size {
+            return;
+        }
+
+        let mut by_expiry: Vec<(String, SystemTime)> = nonces.iter().map(|(k, v)| (k.clone(), *v)).collect();
+        by_expiry.sort_by_key(|(_, valid_at)| *valid_at);
+
+        for (key, _) in by_expiry.into_iter().take(nonces.len() - max_size) {
+            nonces.remove(&key);
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nonces(entries: &[(&str, SystemTime)]) -> Arc<Mutex<HashMap<String, SystemTime>>> {
+        let map = entries.iter().map(|(k, v)| (k.to_string(), *v)).collect();
+        Arc::new(Mutex::new(map))
+    }
+
+    #[tokio::test]
+    async fn test_sweep_removes_expired_entries() {
+        let now = SystemTime::now();
+        let nonces = nonces(&[
+            ("expired", now - Duration::from_secs(1)),
+            ("valid", now + Duration::from_secs(60)),
+        ]);
+
+        ServerTimeLockStore::sweep(&nonces, None).await;
+
+        let remaining = nonces.lock().await;
+        assert_eq!(remaining.len(), 1);
+        assert!(remaining.contains_key("valid"));
+    }
+
+    #[tokio::test]
+    async fn test_sweep_keeps_entry_not_yet_expired() {
+        let now = SystemTime::now();
+        let nonces = nonces(&[("not-yet-expired", now + Duration::from_secs(5))]);
+
+        ServerTimeLockStore::sweep(&nonces, None).await;
+
+        assert!(nonces.lock().await.contains_key("not-yet-expired"));
+    }
+
+    #[tokio::test]
+    async fn test_sweep_is_a_noop_under_max_size() {
+        let now = SystemTime::now();
+        let nonces = nonces(&[
+            ("a", now + Duration::from_secs(10)),
+            ("b", now + Duration::from_secs(20)),
+        ]);
+
+        ServerTimeLockStore::sweep(&nonces, Some(5)).await;
+
+        assert_eq!(nonces.lock().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_sweep_evicts_soonest_to_expire_first_when_over_max_size() {
+        let now = SystemTime::now();
+        let nonces = nonces(&[
+            ("soonest", now + Duration::from_secs(10)),
+            ("middle", now + Duration::from_secs(20)),
+            ("latest", now + Duration::from_secs(30)),
+        ]);
+
+        ServerTimeLockStore::sweep(&nonces, Some(2)).await;
+
+        let remaining = nonces.lock().await;
+        assert_eq!(remaining.len(), 2);
+        assert!(!remaining.contains_key("soonest"));
+        assert!(remaining.contains_key("middle"));
+        assert!(remaining.contains_key("latest"));
+    }
+}
+
 #[async_trait]
 impl ServerTimeLockStoreTrait for ServerTimeLockStore {
     fn lifetime_in_seconds(&self) -> u64 {
@@ -0,0 +1,125 @@
+/// Extracts a named JSON object from within a JSON string without fully parsing it.
+/// This preserves the exact formatting of the JSON for signature verification.
+///
+/// # Arguments
+/// * `data` - The JSON string containing the field
+/// * `label` - The name of the field to extract (e.g., "body" or "payload")
+///
+/// # Returns
+/// The extracted JSON object as a string
+///
+/// # Errors
+/// Returns an error if:
+/// - The label is not found in the data
+/// - The JSON object cannot be fully extracted (malformed JSON)
+pub fn get_sub_json(data: &str, label: &str) -> Result<String, String> {
+    let query = format!("\"{}\":", label);
+
+    let body_start = data
+        .find(&query)
+        .ok_or_else(|| format!("missing {} in response", label))?
+        + query.len();
+
+    let mut brace_count = 0;
+    let mut in_body = false;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut body_end = None;
+
+    for (i, ch) in data[body_start..].char_indices() {
+        let idx = body_start + i;
+
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            '{' => {
+                in_body = true;
+                brace_count += 1;
+            }
+            '}' => {
+                brace_count -= 1;
+                if in_body && brace_count == 0 {
+                    body_end = Some(idx + 1);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let body_end = body_end.ok_or_else(|| format!("failed to extract {} from response", label))?;
+    Ok(data[body_start..body_end].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_sub_json_simple() {
+        let json = r#"{"outer":{"body":{"key":"value"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"key":"value"}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_nested() {
+        let json = r#"{"outer":{"body":{"nested":{"inner":"value"}}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"nested":{"inner":"value"}}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_with_whitespace() {
+        let json = r#"{"outer": {"body": {"key": "value"} }}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"key": "value"}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_missing_label() {
+        let json = r#"{"outer":{"other":{"key":"value"}}}"#;
+        let result = get_sub_json(json, "body");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("missing body"));
+    }
+
+    #[test]
+    fn test_get_sub_json_malformed() {
+        let json = r#"{"body":{"key":"value""#;
+        let result = get_sub_json(json, "body");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("failed to extract"));
+    }
+
+    #[test]
+    fn test_get_sub_json_brace_in_string_value() {
+        let json = r#"{"outer":{"body":{"note":"use {curly} braces here"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"note":"use {curly} braces here"}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_escaped_quote_in_string_value() {
+        let json = r#"{"outer":{"body":{"note":"a \"quoted\" word: }"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"note":"a \"quoted\" word: }"}"#);
+    }
+
+    #[test]
+    fn test_get_sub_json_nested_with_braces_in_strings() {
+        let json = r#"{"outer":{"body":{"nested":{"inner":"{ not json }"},"closing":"}"}}}"#;
+        let result = get_sub_json(json, "body").unwrap();
+        assert_eq!(result, r#"{"nested":{"inner":"{ not json }"},"closing":"}"}"#);
+    }
+}
@@ -4,9 +4,11 @@ use axum::{
     routing::{get, post},
     Router,
 };
+use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tower_http::cors::CorsLayer;
 
 use better_auth::api::server::{
@@ -24,7 +26,6 @@ mod implementation;
 #[derive(Debug)]
 enum AppError {
     Auth(String),
-    Redis(String),
     Permission(String),
     Serialization(String),
     Signing(String),
@@ -34,7 +35,6 @@ impl std::fmt::Display for AppError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             AppError::Auth(e) => write!(f, "Authentication error: {}", e),
-            AppError::Redis(e) => write!(f, "Redis error: {}", e),
             AppError::Permission(e) => write!(f, "Permission error: {}", e),
             AppError::Serialization(e) => write!(f, "Serialization error: {}", e),
             AppError::Signing(e) => write!(f, "Signing error: {}", e),
@@ -52,7 +52,6 @@ impl From<AppError> for BetterAuthError {
     fn from(err: AppError) -> BetterAuthError {
         match err {
             AppError::Auth(e) => BetterAuthError::new("APP001", format!("Auth: {}", e)),
-            AppError::Redis(e) => BetterAuthError::new("APP002", format!("Redis: {}", e)),
             AppError::Permission(e) => BetterAuthError::new("APP003", format!("Permission: {}", e)),
             AppError::Serialization(e) => BetterAuthError::new("APP004", format!("Serialization: {}", e)),
             AppError::Signing(e) => BetterAuthError::new("APP005", format!("Signing: {}", e)),
@@ -61,10 +60,90 @@ impl From<AppError> for BetterAuthError {
 }
 
 use implementation::{
-    Rfc3339, RedisVerificationKeyStore, Secp256r1, Secp256r1Verifier, ServerTimeLockStore,
-    TokenEncoder,
+    AuthorizedKey, RedisRevocationStore, Rfc3339, RedisVerificationKeyStore, ResponseKeyAuthorizer,
+    ResponseKeyRotator, RevocationStore, RotationConfig, Secp256r1, Secp256r1Verifier,
+    ServerTimeLockStore, TokenEncoder,
 };
 
+/// Authorizes a new response key by generating it, signing it with the HSM
+/// over HTTP, and registering it in the response-keys Redis DB; also backs
+/// [`ResponseKeyRotator`]'s overlap handling by shrinking a superseded
+/// key's remaining TTL instead of leaving it registered for its full
+/// original lifetime.
+struct HttpResponseKeyAuthorizer {
+    hsm_url: String,
+    ttl_seconds: i64,
+    connection: tokio::sync::Mutex<redis::aio::ConnectionManager>,
+}
+
+impl HttpResponseKeyAuthorizer {
+    fn new(hsm_url: String, ttl_seconds: i64, connection: redis::aio::ConnectionManager) -> Self {
+        Self {
+            hsm_url,
+            ttl_seconds,
+            connection: tokio::sync::Mutex::new(connection),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ResponseKeyAuthorizer for HttpResponseKeyAuthorizer {
+    async fn authorize_new_key(&self) -> Result<AuthorizedKey, String> {
+        let mut key = Secp256r1::new();
+        key.generate().map_err(|e| format!("failed to generate response key: {}", e))?;
+        let public_key = key.public().await.map_err(|e| format!("failed to read public key: {}", e))?;
+
+        let expiration = chrono::Utc::now() + chrono::Duration::seconds(self.ttl_seconds);
+        let expiration_str = expiration.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true);
+
+        // Build JSON manually for deterministic ordering: purpose, publicKey, expiration
+        let payload_json = format!(
+            r#"{{"purpose":"response","publicKey":"{}","expiration":"{}"}}"#,
+            public_key, expiration_str
+        );
+        let request_json = format!(r#"{{"payload":{}}}"#, payload_json);
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(format!("{}/sign", self.hsm_url))
+            .header("Content-Type", "application/json")
+            .body(request_json)
+            .send()
+            .await
+            .map_err(|e| format!("failed to contact HSM: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("failed to sign response key with HSM: {}", response.status()));
+        }
+
+        let authorization = response
+            .text()
+            .await
+            .map_err(|e| format!("failed to read HSM response: {}", e))?
+            .trim_end()
+            .to_string();
+
+        let mut conn = self.connection.lock().await;
+        conn.set_ex::<_, _, ()>(&public_key, &authorization, self.ttl_seconds as u64)
+            .await
+            .map_err(|e| format!("failed to register response key: {}", e))?;
+        drop(conn);
+
+        Ok(AuthorizedKey {
+            key,
+            public_key,
+            expiration,
+        })
+    }
+
+    async fn shrink_validity(&self, public_key: &str, ttl: chrono::Duration) -> Result<(), String> {
+        let mut conn = self.connection.lock().await;
+        conn.expire::<_, ()>(public_key, ttl.num_seconds().max(1))
+            .await
+            .map_err(|e| format!("failed to shrink response key validity: {}", e))
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 struct TokenAttributes {
     #[serde(rename = "permissionsByRole")]
@@ -95,8 +174,7 @@ struct HealthResponse {
 #[derive(Clone)]
 struct AppState {
     av: Arc<AccessVerifier>,
-    response_key: Arc<Secp256r1>,
-    revoked_devices_client: redis::aio::ConnectionManager,
+    response_key: Arc<ResponseKeyRotator>,
 }
 
 async fn health() -> (StatusCode, axum::Json<HealthResponse>) {
@@ -123,22 +201,11 @@ async fn foo_bar(State(state): State<AppState>, body: String) -> (StatusCode, St
 }
 
 async fn handle_foo_bar(state: &AppState, message: String) -> Result<String, AppError> {
-    // Verify the access request
+    // Verify the access request. Revocation is enforced inside this call by
+    // the access key store, so a revoked device's token never decodes here.
     let (request, token, nonce): (RequestPayload, AccessToken<TokenAttributes>, String) =
         state.av.verify(&message).await.map_err(|e| AppError::Auth(e.to_string()))?;
 
-    // Check if device is revoked
-    use redis::AsyncCommands;
-    let mut conn = state.revoked_devices_client.clone();
-    let is_revoked: bool = conn
-        .exists(&token.device)
-        .await
-        .map_err(|e| AppError::Redis(format!("Failed to check revoked devices: {}", e)))?;
-
-    if is_revoked {
-        return Err(AppError::Permission("device revoked".to_string()));
-    }
-
     // Check permissions
     if let Some(user_permissions) = token.attributes.permissions_by_role.get("user") {
         if !user_permissions.contains(&"read".to_string()) {
@@ -149,7 +216,8 @@ async fn handle_foo_bar(state: &AppState, message: String) -> Result<String, App
     }
 
     // Get server identity
-    let server_identity = state.response_key.identity().await.map_err(AppError::Auth)?;
+    let response_key = state.response_key.current();
+    let server_identity = response_key.identity().await.map_err(AppError::Auth)?;
 
     // Create response
     let mut response: ServerResponse<ResponsePayload> = ServerResponse::new(
@@ -163,7 +231,7 @@ async fn handle_foo_bar(state: &AppState, message: String) -> Result<String, App
     );
 
     // Sign the response - no conversion needed! Library handles it via Into<BetterAuthError>
-    response.sign(state.response_key.as_ref()).await.map_err(|e| AppError::Signing(e.to_string()))?;
+    response.sign(response_key.as_ref()).await.map_err(|e| AppError::Signing(e.to_string()))?;
 
     // Serialize to JSON - no conversion needed! Library handles it via Into<BetterAuthError>
     response.to_json().await.map_err(|e| AppError::Serialization(e.to_string()))
@@ -191,6 +259,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or_else(|_| "4".to_string())
         .parse()
         .unwrap_or(4);
+    let redis_pool_size: u32 = std::env::var("REDIS_POOL_SIZE")
+        .unwrap_or_else(|_| "16".to_string())
+        .parse()
+        .unwrap_or(16);
+    let redis_key_namespace = std::env::var("REDIS_KEY_NAMESPACE").unwrap_or_default();
 
     println!("Connecting to Redis at {}", redis_host);
     println!("Access keys DB: {}", redis_db_access_keys);
@@ -204,25 +277,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let revoked_devices_redis_url = format!("redis://{}/{}", redis_host, redis_db_revoked_devices);
     let hsm_redis_url = format!("redis://{}/{}", redis_host, redis_db_hsm_keys);
 
-    let access_client = redis::Client::open(access_redis_url.as_str())?;
     let response_client = redis::Client::open(response_redis_url.as_str())?;
     let revoked_devices_client = redis::Client::open(revoked_devices_redis_url.as_str())?;
     let hsm_client = redis::Client::open(hsm_redis_url.as_str())?;
 
-    let access_conn = access_client
-        .get_connection_manager()
-        .await
-        .map_err(|e| format!("Failed to connect to Redis (access): {}", e))?;
-    let revoked_devices_conn = revoked_devices_client
-        .get_connection_manager()
-        .await
-        .map_err(|e| format!("Failed to connect to Redis (revoked devices): {}", e))?;
     let hsm_conn = hsm_client
         .get_connection_manager()
         .await
         .map_err(|e| format!("Failed to connect to Redis (HSM keys): {}", e))?;
-    let mut response_conn = response_client
-        .get_connection()
+    let response_conn = response_client
+        .get_connection_manager()
+        .await
         .map_err(|e| format!("Failed to connect to Redis (response): {}", e))?;
 
     println!("Connected to Redis");
@@ -235,89 +300,82 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let server_lifetime_hours = 12;
     let access_lifetime_minutes = 15;
     let access_nonce_store = ServerTimeLockStore::new(access_window);
-    let access_key_store = RedisVerificationKeyStore::new(access_conn, hsm_conn, server_lifetime_hours, access_lifetime_minutes);
+
+    let revocation_store: Arc<RedisRevocationStore> = Arc::new(
+        RedisRevocationStore::new(&revoked_devices_redis_url, redis_pool_size, redis_key_namespace.clone())
+            .await
+            .map_err(|e| format!("Failed to build revocation store: {}", e))?,
+    );
+    // Reconcile the full revoked-device list before serving any traffic, so
+    // a device revoked before this process started isn't treated as clear
+    // just because it never showed up on the pub/sub channel.
+    revocation_store
+        .sync()
+        .await
+        .map_err(|e| format!("Failed to sync revocation list: {}", e))?;
+    revocation_store.spawn_subscriber(revoked_devices_client, "revoked-devices".to_string());
+    revocation_store.spawn_periodic_sync(Duration::from_secs(300));
+
+    let access_key_store = RedisVerificationKeyStore::new(
+        &access_redis_url,
+        hsm_conn,
+        server_lifetime_hours,
+        access_lifetime_minutes,
+        redis_pool_size,
+        redis_key_namespace,
+    )
+    .await
+    .map_err(|e| format!("Failed to build access key store: {}", e))?
+    .with_revocation_store(Arc::clone(&revocation_store) as Arc<dyn RevocationStore>);
 
     // Create encoding components
     let timestamper = Rfc3339::new();
     let token_encoder = TokenEncoder::new();
 
-    // Generate and register response key
-    let mut response_key = Secp256r1::new();
-    response_key.generate()?;
-    let response_public_key = response_key.public().await?;
-
-    // Sign response key with HSM
+    // Generate and register the response key, then keep it rotating in the
+    // background so the server never has to be restarted before it expires.
     let hsm_host = std::env::var("HSM_HOST").unwrap_or_else(|_| "hsm".to_string());
     let hsm_port = std::env::var("HSM_PORT").unwrap_or_else(|_| "11111".to_string());
     let hsm_url = format!("http://{}:{}", hsm_host, hsm_port);
+    let response_key_ttl_seconds: i64 = 12 * 60 * 60 + 60; // 43260 seconds
+
+    let response_key_rotate_before_expiry_minutes: i64 =
+        std::env::var("RESPONSE_KEY_ROTATE_BEFORE_EXPIRY_MINUTES")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .unwrap_or(30);
+    let response_key_overlap_minutes: i64 = std::env::var("RESPONSE_KEY_OVERLAP_MINUTES")
+        .unwrap_or_else(|_| "5".to_string())
+        .parse()
+        .unwrap_or(5);
 
-    let ttl_seconds = 12 * 60 * 60 + 60; // 43260 seconds
-    let response_expiration = chrono::Utc::now() + chrono::Duration::seconds(ttl_seconds as i64);
-    let expiration_str = response_expiration.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true);
-
-    // Build JSON manually for deterministic ordering: purpose, publicKey, expiration
-    let response_payload_json = format!(
-        r#"{{"purpose":"response","publicKey":"{}","expiration":"{}"}}"#,
-        response_public_key, expiration_str
-    );
-
-    let hsm_request_json = format!(r#"{{"payload":{}}}"#, response_payload_json);
+    let response_key_authorizer: Arc<dyn ResponseKeyAuthorizer> = Arc::new(HttpResponseKeyAuthorizer::new(
+        hsm_url,
+        response_key_ttl_seconds,
+        response_conn,
+    ));
 
-    let client = reqwest::Client::new();
-    let authorization = match client
-        .post(format!("{}/sign", hsm_url))
-        .header("Content-Type", "application/json")
-        .body(hsm_request_json)
-        .send()
+    let initial_response_key = response_key_authorizer
+        .authorize_new_key()
         .await
-    {
-        Ok(resp) if resp.status().is_success() => {
-            match resp.text().await {
-                Ok(text) => {
-                    let trimmed = text.trim_end().to_string();
-                    println!("Response key HSM authorization: {}", trimmed);
-                    Some(trimmed)
-                }
-                Err(e) => {
-                    println!("Warning: Failed to read HSM response: {}", e);
-                    None
-                }
-            }
-        }
-        Ok(resp) => {
-            println!(
-                "Warning: Failed to sign response key with HSM: {}",
-                resp.status()
-            );
-            None
-        }
-        Err(e) => {
-            println!("Warning: Failed to contact HSM: {}", e);
-            None
-        }
-    };
+        .map_err(|e| format!("Failed to obtain initial response key authorization: {}", e))?;
 
-    // Store the full HSM authorization in Redis DB 1 with 12 hour 1 minute TTL
-    if let Some(auth) = authorization {
-        redis::cmd("SET")
-            .arg(&response_public_key)
-            .arg(&auth)
-            .arg("EX")
-            .arg(ttl_seconds)
-            .query::<()>(&mut response_conn)
-            .map_err(|e| format!("Failed to register response key: {}", e))?;
-
-        println!(
-            "Registered app response key in Redis DB {} (TTL: 12 hours): {}...",
-            redis_db_response_keys,
-            &response_public_key[..20]
-        );
-    } else {
-        println!("Warning: No HSM authorization to store in Redis");
-    }
+    println!(
+        "Registered app response key in Redis DB {} (TTL: 12 hours): {}...",
+        redis_db_response_keys,
+        &initial_response_key.public_key[..20]
+    );
 
-    // Drop response connection (we don't need it anymore)
-    drop(response_conn);
+    let response_key = ResponseKeyRotator::spawn(
+        response_key_authorizer,
+        initial_response_key,
+        RotationConfig {
+            rotate_before_expiry: chrono::Duration::minutes(response_key_rotate_before_expiry_minutes),
+            overlap: chrono::Duration::minutes(response_key_overlap_minutes),
+            ..RotationConfig::default()
+        },
+        |e| eprintln!("Warning: {}", e),
+    );
 
     // Create AccessVerifier
     let av = AccessVerifier {
@@ -340,8 +398,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let state = AppState {
         av: Arc::new(av),
-        response_key: Arc::new(response_key),
-        revoked_devices_client: revoked_devices_conn,
+        response_key,
     };
 
     println!("Application server initialized");